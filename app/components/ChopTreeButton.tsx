@@ -4,8 +4,20 @@ import { Button, HStack, VStack } from "@chakra-ui/react";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { useSessionWallet } from "@magicblock-labs/gum-react-sdk";
 import { useGameState } from "@/contexts/GameStateProvider";
-import { GAME_DATA_SEED, gameDataPDA, useProgram } from "@/utils/anchor";
+import {
+  GAME_DATA_SEED,
+  gameConfigPDA,
+  gameDataPDA,
+  nextNonce,
+  useProgram,
+  woodMintPDA,
+} from "@/utils/anchor";
 import { SystemProgram } from "@solana/web3.js";
+import {
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
 
 const ChopTreeButton = () => {
   const { publicKey, sendTransaction } = useWallet();
@@ -14,23 +26,31 @@ const ChopTreeButton = () => {
   const { gameState, playerDataPDA } = useGameState();
   const [isLoadingSession, setIsLoadingSession] = useState(false);
   const [isLoadingMainWallet, setIsLoadingMainWallet] = useState(false);
-  const [transactionCounter, setTransactionCounter] = useState(0);
 
   const program = useProgram();
 
   const onChopClick = useCallback(async () => {
+    if (!playerDataPDA || !sessionWallet || !publicKey || !gameState) return;
     setIsLoadingSession(true);
-    if (!playerDataPDA || !sessionWallet) return;
-    setTransactionCounter(transactionCounter + 1);
 
     try {
       const transaction = await program.methods
-        .chopTree(GAME_DATA_SEED, transactionCounter)
+        .chopTree(GAME_DATA_SEED, nextNonce(gameState.lastId), 1)
         .accountsPartial({
           player: playerDataPDA,
           gameData: gameDataPDA,
+          gameConfig: gameConfigPDA,
           signer: sessionWallet.publicKey!,
           sessionToken: sessionWallet.sessionToken,
+          playerAuthority: publicKey,
+          playerWoodAccount: getAssociatedTokenAddressSync(
+            woodMintPDA,
+            publicKey
+          ),
+          woodMint: woodMintPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .transaction();
 
@@ -46,21 +66,31 @@ const ChopTreeButton = () => {
     } finally {
       setIsLoadingSession(false);
     }
-  }, [sessionWallet]);
+  }, [sessionWallet, playerDataPDA, gameState, publicKey, program]);
 
   const onChopMainWalletClick = useCallback(async () => {
-    if (!publicKey || !playerDataPDA) return;
+    if (!publicKey || !playerDataPDA || !gameState) return;
 
     setIsLoadingMainWallet(true);
 
     try {
       const transaction = await program.methods
-        .chopTree(GAME_DATA_SEED, transactionCounter)
+        .chopTree(GAME_DATA_SEED, nextNonce(gameState.lastId), 1)
         .accountsPartial({
           player: playerDataPDA,
           gameData: gameDataPDA,
+          gameConfig: gameConfigPDA,
           signer: publicKey,
           sessionToken: null,
+          playerAuthority: publicKey,
+          playerWoodAccount: getAssociatedTokenAddressSync(
+            woodMintPDA,
+            publicKey
+          ),
+          woodMint: woodMintPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
         })
         .transaction();
 
@@ -73,7 +103,14 @@ const ChopTreeButton = () => {
     } finally {
       setIsLoadingMainWallet(false);
     }
-  }, [publicKey, playerDataPDA, connection]);
+  }, [
+    publicKey,
+    playerDataPDA,
+    gameState,
+    connection,
+    sendTransaction,
+    program,
+  ]);
 
   return (
     <>
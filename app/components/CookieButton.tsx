@@ -4,8 +4,17 @@ import { Button, HStack, VStack, Text } from "@chakra-ui/react";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { useSessionWallet } from "@magicblock-labs/gum-react-sdk";
 import { useGameState } from "@/contexts/GameStateProvider";
-import { useProgram, PROGRAM_ID } from "@/utils/anchor";
-import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  gameConfigPDA,
+  nextNonce,
+  useProgram,
+  PROGRAM_ID,
+} from "@/utils/anchor";
+import {
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 import {
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
@@ -109,7 +118,7 @@ const CookieButton = ({ onBalanceUpdate }: CookieButtonProps = {}) => {
 
   // Session wallet click (fast, no approval needed)
   const onClickCookieSession = useCallback(async () => {
-    if (!playerDataPDA || !sessionWallet || !publicKey) return;
+    if (!playerDataPDA || !sessionWallet || !publicKey || !gameState) return;
 
     setIsLoadingSession(true);
 
@@ -121,14 +130,16 @@ const CookieButton = ({ onBalanceUpdate }: CookieButtonProps = {}) => {
       }
 
       const transaction = await program.methods
-        .onClick()
+        .onClick(nextNonce(gameState.lastId))
         .accountsPartial({
           player: playerDataPDA,
+          gameConfig: gameConfigPDA,
           playerAuthority: publicKey,
           playerTokenAccount: playerTokenAccount,
           rewardTokenMint: cookieMintPDA,
           signer: sessionWallet.publicKey!,
           sessionToken: sessionWallet.sessionToken,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -152,6 +163,7 @@ const CookieButton = ({ onBalanceUpdate }: CookieButtonProps = {}) => {
   }, [
     sessionWallet,
     playerDataPDA,
+    gameState,
     publicKey,
     program,
     getPlayerTokenAccount,
@@ -160,7 +172,7 @@ const CookieButton = ({ onBalanceUpdate }: CookieButtonProps = {}) => {
 
   // Main wallet click (requires approval)
   const onClickCookieMainWallet = useCallback(async () => {
-    if (!publicKey || !playerDataPDA) return;
+    if (!publicKey || !playerDataPDA || !gameState) return;
 
     setIsLoadingMainWallet(true);
 
@@ -172,14 +184,16 @@ const CookieButton = ({ onBalanceUpdate }: CookieButtonProps = {}) => {
       }
 
       const transaction = await program.methods
-        .onClick()
+        .onClick(nextNonce(gameState.lastId))
         .accountsPartial({
           player: playerDataPDA,
+          gameConfig: gameConfigPDA,
           playerAuthority: publicKey,
           playerTokenAccount: playerTokenAccount,
           rewardTokenMint: cookieMintPDA,
           signer: publicKey,
           sessionToken: null, // No session token when using main wallet
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -205,6 +219,7 @@ const CookieButton = ({ onBalanceUpdate }: CookieButtonProps = {}) => {
   }, [
     publicKey,
     playerDataPDA,
+    gameState,
     connection,
     sendTransaction,
     program,
@@ -24,6 +24,8 @@ import {
   calculateCreationCost,
   calculateProductionRate,
   getMaxAccumulationHours,
+  getPluginPDA,
+  getPluginRegistryPDA,
   TIER_NAMES,
 } from "@/utils/pluginHelpers";
 import { getAssociatedTokenAddressSync } from "@solana/spl-token";
@@ -84,22 +86,11 @@ const CreatePlugin = ({ onPluginCreated }: CreatePluginProps) => {
       const metadataUri =
         metadata.length <= 200 ? metadata : `{"name":"${name}","tier":${tier}}`;
 
-      const [playerDataPDA] = PublicKey.findProgramAddressSync(
-        [Buffer.from("player"), publicKey.toBuffer()],
-        program.programId,
-      );
-
-      // Fetch current plugin counter to derive the next plugin PDA
-      const playerData = await program.account.playerData.fetch(playerDataPDA);
-      const pluginId = playerData.pluginGlobalCounter.toNumber();
-
-      const [pluginPDA] = PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("plugin"),
-          new Uint8Array(new BigInt64Array([BigInt(pluginId)]).buffer),
-        ],
-        program.programId,
-      );
+      // The global registry hands out the next plugin ID
+      const pluginRegistryPDA = getPluginRegistryPDA();
+      const registry =
+        await program.account.pluginRegistry.fetch(pluginRegistryPDA);
+      const pluginPDA = getPluginPDA(registry.nextPluginId.toNumber());
 
       const creatorTokenAccount = getAssociatedTokenAddressSync(
         cookieMintPDA,
@@ -110,8 +101,10 @@ const CreatePlugin = ({ onPluginCreated }: CreatePluginProps) => {
 
       const tx = await program.methods
         .createPlugin(tier, metadataUri, creatorShareBps)
-        .accounts({
+        .accountsPartial({
           creator: publicKey,
+          pluginRegistry: pluginRegistryPDA,
+          plugin: pluginPDA,
         })
         .rpc();
 
@@ -11,6 +11,12 @@ const DisplayPlayerData = () => {
     <>
       {gameState && publicKey && (
         <HStack justifyContent="center" spacing={4}>
+          <Text fontSize="lg" fontWeight="semibold">
+            {gameState.name ||
+              `${publicKey.toBase58().slice(0, 4)}...${publicKey
+                .toBase58()
+                .slice(-4)}`}
+          </Text>
           <HStack>
             <Image src="/energy.png" alt="Energy Icon" width={64} height={64} />
             <VStack align="start" spacing={0}>
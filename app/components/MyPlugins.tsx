@@ -23,6 +23,7 @@ import {
   calculateAccumulatedCookies,
   calculateProductionRate,
   getMaxAccumulationHours,
+  getPluginPDA,
   formatTimeRemaining,
   TIER_COLORS,
   TIER_NAMES,
@@ -112,17 +113,7 @@ const MyPlugins = forwardRef<{ refresh: () => void }, {}>((props, ref) => {
             // Fetch plugin metadata
             let metadata: PluginMetadata | undefined;
             try {
-              const [pluginPDA] = PublicKey.findProgramAddressSync(
-                [
-                  Buffer.from("plugin"),
-                  new Uint8Array(
-                    new BigInt64Array([
-                      BigInt(slotData.pluginId.toNumber()),
-                    ]).buffer
-                  ),
-                ],
-                program.programId
-              );
+              const pluginPDA = getPluginPDA(slotData.pluginId.toNumber());
               const pluginData = await program.account.plugin.fetch(pluginPDA);
               metadata = JSON.parse(pluginData.metadataUri);
             } catch (e) {
@@ -162,7 +153,7 @@ const MyPlugins = forwardRef<{ refresh: () => void }, {}>((props, ref) => {
     refresh: fetchMyPlugins,
   }));
 
-  const claimCookies = async (tier: number) => {
+  const claimCookies = async (tier: number, pluginId: number) => {
     if (!publicKey) return;
 
     setClaimingTier(tier);
@@ -187,6 +178,7 @@ const MyPlugins = forwardRef<{ refresh: () => void }, {}>((props, ref) => {
         .accounts({
           player: publicKey,
           playerPluginSlot: playerPluginSlotPDA,
+          plugin: getPluginPDA(pluginId),
           playerTokenAccount,
           cookieMint: cookieMintPDA,
         })
@@ -319,7 +311,7 @@ const MyPlugins = forwardRef<{ refresh: () => void }, {}>((props, ref) => {
 
               <Button
                 colorScheme={accumulated > 0 ? "green" : "gray"}
-                onClick={() => claimCookies(slot.tier)}
+                onClick={() => claimCookies(slot.tier, slot.pluginId)}
                 isLoading={claimingTier === slot.tier}
                 isDisabled={accumulated === 0 || claimingTier !== null}
                 size="sm"
@@ -23,12 +23,14 @@ import { useProgram } from "@/utils/anchor";
 import {
   calculateInstallCost,
   calculateProductionRate,
+  getAverageRating,
   getMaxAccumulationHours,
+  getPluginPDA,
   TIER_COLORS,
   TIER_NAMES,
   isTierUnlocked,
 } from "@/utils/pluginHelpers";
-import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
 
 interface PluginMetadata {
   name?: string;
@@ -48,6 +50,9 @@ interface Plugin {
   createdAt: number;
   creatorShareBps: number;
   burnShareBps: number;
+  averageRating: number;
+  ratingCount: number;
+  nftMint: PublicKey | null;
   metadata?: PluginMetadata;
 }
 
@@ -84,7 +89,11 @@ const PluginShop = forwardRef<{ refresh: () => void }, PluginShopProps>(
       setLoading(true);
       try {
         const pluginAccounts = await program.account.plugin.all();
-        const pluginData = pluginAccounts.map((account: any) => {
+        // Delisted, hidden and banned plugins can't be installed
+        const listedAccounts = pluginAccounts.filter(
+          (account: any) => account.account.status.active !== undefined
+        );
+        const pluginData = listedAccounts.map((account: any) => {
           // Parse metadata JSON
           let metadata: PluginMetadata | undefined;
           try {
@@ -103,13 +112,22 @@ const PluginShop = forwardRef<{ refresh: () => void }, PluginShopProps>(
             createdAt: account.account.createdAt.toNumber(),
             creatorShareBps: account.account.creatorShareBps,
             burnShareBps: account.account.burnShareBps,
+            averageRating: getAverageRating(
+              account.account.ratingSum.toNumber(),
+              account.account.ratingCount.toNumber()
+            ),
+            ratingCount: account.account.ratingCount.toNumber(),
+            nftMint: account.account.nftMint,
             metadata,
           };
         });
 
-        // Sort by tier, then by total installs
+        // Sort by tier, then by rating, then by total installs
         pluginData.sort((a, b) => {
           if (a.tier !== b.tier) return a.tier - b.tier;
+          if (a.averageRating !== b.averageRating) {
+            return b.averageRating - a.averageRating;
+          }
           return b.totalInstalls - a.totalInstalls;
         });
 
@@ -193,13 +211,7 @@ const PluginShop = forwardRef<{ refresh: () => void }, PluginShopProps>(
           program.programId
         );
 
-        const [pluginPDA] = PublicKey.findProgramAddressSync(
-          [
-            Buffer.from("plugin"),
-            new Uint8Array(new BigInt64Array([BigInt(plugin.pluginId)]).buffer),
-          ],
-          program.programId
-        );
+        const pluginPDA = getPluginPDA(plugin.pluginId);
 
         const [playerPluginSlotPDA] = PublicKey.findProgramAddressSync(
           [
@@ -215,9 +227,34 @@ const PluginShop = forwardRef<{ refresh: () => void }, PluginShopProps>(
           publicKey
         );
 
+        // Replacing a different plugin settles it, so it has to be passed in
+        const currentSlot =
+          await program.account.playerPluginSlot.fetchNullable(
+            playerPluginSlotPDA
+          );
+        const currentPluginId = currentSlot?.pluginId.toNumber() ?? 0;
+        const previousPlugin =
+          currentPluginId !== 0 && currentPluginId !== plugin.pluginId
+            ? getPluginPDA(currentPluginId)
+            : null;
+
+        // Install revenue goes to the plugin NFT holder, if there is one
+        let revenueRecipient = plugin.creator;
+        let nftHolderAccount: PublicKey | null = null;
+        if (plugin.nftMint) {
+          const { value: holders } = await connection.getTokenLargestAccounts(
+            plugin.nftMint
+          );
+          const holder = holders.find((account) => account.amount === "1");
+          if (!holder) throw new Error("Plugin NFT holder not found");
+          nftHolderAccount = holder.address;
+          const holderInfo = await getAccount(connection, holder.address);
+          revenueRecipient = holderInfo.owner;
+        }
+
         const creatorTokenAccount = getAssociatedTokenAddressSync(
           cookieMintPDA,
-          plugin.creator
+          revenueRecipient
         );
 
         console.log("📦 Installing plugin...", {
@@ -228,8 +265,12 @@ const PluginShop = forwardRef<{ refresh: () => void }, PluginShopProps>(
 
         const tx = await program.methods
           .installPlugin()
-          .accounts({
+          .accountsPartial({
             player: publicKey,
+            plugin: pluginPDA,
+            previousPlugin,
+            creatorTokenAccount,
+            nftHolderAccount,
           })
           .rpc();
 
@@ -333,9 +374,17 @@ const PluginShop = forwardRef<{ refresh: () => void }, PluginShopProps>(
                       <Badge colorScheme="purple">
                         Tier {plugin.tier} - {TIER_NAMES[plugin.tier]}
                       </Badge>
-                      <Badge colorScheme="green">
-                        {plugin.totalInstalls} installs
-                      </Badge>
+                      <HStack spacing={1}>
+                        {plugin.ratingCount > 0 && (
+                          <Badge colorScheme="yellow">
+                            ★ {plugin.averageRating.toFixed(1)} (
+                            {plugin.ratingCount})
+                          </Badge>
+                        )}
+                        <Badge colorScheme="green">
+                          {plugin.totalInstalls} installs
+                        </Badge>
+                      </HStack>
                     </HStack>
 
                     <HStack justify="space-between" align="start">
@@ -13,6 +13,44 @@ export type Cookie = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "acceptAdmin",
+      "discriminator": [
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "newAdmin",
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
     {
       "name": "chopTree",
       "discriminator": [
@@ -66,11 +104,147 @@ export type Cookie = {
             ]
           }
         },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "signer",
           "writable": true,
           "signer": true
         },
+        {
+          "name": "playerAuthority"
+        },
+        {
+          "name": "playerWoodAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "playerAuthority"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "woodMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "woodMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  111,
+                  111,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
@@ -84,35 +258,33 @@ export type Cookie = {
         {
           "name": "counter",
           "type": "u16"
+        },
+        {
+          "name": "chops",
+          "type": "u16"
         }
       ]
     },
     {
-      "name": "claimPluginCookies",
+      "name": "claimDailyReward",
       "discriminator": [
+        24,
+        130,
+        79,
+        89,
+        83,
+        137,
         178,
-        208,
-        117,
-        84,
-        179,
-        177,
-        229,
-        252
+        108
       ],
       "accounts": [
         {
           "name": "player",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "playerPluginSlot"
-          ]
+          "signer": true
         },
         {
-          "name": "playerPluginSlot",
-          "docs": [
-            "Player's plugin slot for this tier"
-          ],
+          "name": "playerData",
           "writable": true,
           "pda": {
             "seeds": [
@@ -124,28 +296,30 @@ export type Cookie = {
                   97,
                   121,
                   101,
-                  114,
-                  95,
-                  112,
-                  108,
-                  117,
-                  103,
-                  105,
-                  110,
-                  95,
-                  115,
-                  108,
-                  111,
-                  116
+                  114
                 ]
               },
               {
                 "kind": "account",
                 "path": "player"
-              },
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
               {
-                "kind": "arg",
-                "path": "tier"
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
               }
             ]
           }
@@ -265,265 +439,120 @@ export type Cookie = {
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "tier",
-          "type": "u8"
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "createMint",
+      "name": "claimGoldenCookie",
       "discriminator": [
-        69,
-        44,
-        215,
-        132,
-        253,
-        214,
-        41,
-        45
+        81,
+        85,
+        51,
+        77,
+        226,
+        170,
+        236,
+        247
       ],
       "accounts": [
         {
-          "name": "admin",
-          "docs": [
-            "The admin account that can create the mint",
-            "TODO: Add address constraint with proper admin pubkey"
-          ],
-          "writable": true,
-          "signer": true
+          "name": "sessionToken",
+          "optional": true
         },
         {
-          "name": "rewardTokenMint",
+          "name": "player",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  114,
-                  101,
-                  119,
+                  112,
+                  108,
                   97,
-                  114,
-                  100
+                  121,
+                  101,
+                  114
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "player.authority",
+                "account": "playerData"
               }
             ]
           }
         },
         {
-          "name": "metadataAccount",
-          "writable": true,
+          "name": "gameConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  101,
-                  116,
-                  97,
-                  100,
-                  97,
-                  116,
-                  97
-                ]
-              },
-              {
-                "kind": "const",
-                "value": [
-                  11,
-                  112,
-                  101,
-                  177,
-                  227,
-                  209,
-                  124,
-                  69,
-                  56,
-                  157,
-                  82,
-                  127,
-                  107,
-                  4,
-                  195,
-                  205,
-                  88,
-                  184,
-                  108,
-                  115,
-                  26,
-                  160,
-                  253,
-                  181,
-                  73,
-                  182,
-                  209,
-                  188,
-                  3,
-                  248,
-                  41,
-                  70
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "rewardTokenMint"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                11,
-                112,
-                101,
-                177,
-                227,
-                209,
-                124,
-                69,
-                56,
-                157,
-                82,
-                127,
-                107,
-                4,
-                195,
-                205,
-                88,
-                184,
-                108,
-                115,
-                26,
-                160,
-                253,
-                181,
-                73,
-                182,
-                209,
-                188,
-                3,
-                248,
-                41,
-                70
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "tokenMetadataProgram"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        },
-        {
-          "name": "rent",
-          "address": "SysvarRent111111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "uri",
-          "type": "string"
-        },
-        {
-          "name": "name",
-          "type": "string"
-        },
-        {
-          "name": "symbol",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "createPlugin",
-      "discriminator": [
-        210,
-        213,
-        78,
-        20,
-        110,
-        209,
-        221,
-        41
-      ],
-      "accounts": [
-        {
-          "name": "creator",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "playerData",
+          "name": "goldenCookie",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
+                  103,
+                  111,
                   108,
-                  97,
-                  121,
+                  100,
                   101,
-                  114
+                  110,
+                  95,
+                  99,
+                  111,
+                  111,
+                  107,
+                  105,
+                  101
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "creator"
               }
             ]
           }
         },
         {
-          "name": "plugin",
-          "docs": [
-            "Plugin account - PDA seeded by plugin_id"
-          ],
+          "name": "signer",
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  108,
-                  117,
-                  103,
-                  105,
-                  110
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "player_data.plugin_global_counter",
-                "account": "playerData"
-              }
-            ]
-          }
+          "signer": true
         },
         {
-          "name": "creatorTokenAccount",
-          "docs": [
-            "Creator's cookie token account (to burn creation cost)"
-          ],
+          "name": "playerAuthority",
+          "writable": true
+        },
+        {
+          "name": "playerTokenAccount",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "creator"
+                "path": "playerAuthority"
               },
               {
                 "kind": "const",
@@ -564,7 +593,7 @@ export type Cookie = {
               },
               {
                 "kind": "account",
-                "path": "cookieMint"
+                "path": "rewardTokenMint"
               }
             ],
             "program": {
@@ -607,7 +636,7 @@ export type Cookie = {
           }
         },
         {
-          "name": "cookieMint",
+          "name": "rewardTokenMint",
           "writable": true,
           "pda": {
             "seeds": [
@@ -625,46 +654,49 @@ export type Cookie = {
             ]
           }
         },
+        {
+          "name": "slotHashes",
+          "address": "SysvarS1otHashes111111111111111111111111111"
+        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
         },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "tier",
-          "type": "u8"
-        },
-        {
-          "name": "metadataUri",
-          "type": "string"
-        },
-        {
-          "name": "creatorShareBps",
-          "type": "u16"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initPlayer",
+      "name": "claimPluginCookies",
       "discriminator": [
-        114,
-        27,
-        219,
-        144,
-        50,
-        15,
-        228,
-        66
+        178,
+        208,
+        117,
+        84,
+        179,
+        177,
+        229,
+        252
       ],
       "accounts": [
         {
           "name": "player",
           "writable": true,
+          "signer": true,
+          "relations": [
+            "playerPluginSlot"
+          ]
+        },
+        {
+          "name": "playerData",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -680,60 +712,17 @@ export type Cookie = {
               },
               {
                 "kind": "account",
-                "path": "signer"
+                "path": "player"
               }
             ]
           }
         },
         {
-          "name": "gameData",
+          "name": "playerPluginSlot",
+          "docs": [
+            "Player's plugin slot for this tier"
+          ],
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "arg",
-                "path": "levelSeed"
-              }
-            ]
-          }
-        },
-        {
-          "name": "signer",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "levelSeed",
-          "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "installPlugin",
-      "discriminator": [
-        136,
-        37,
-        116,
-        68,
-        189,
-        124,
-        6,
-        99
-      ],
-      "accounts": [
-        {
-          "name": "player",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "playerData",
           "pda": {
             "seeds": [
               {
@@ -744,12 +733,28 @@ export type Cookie = {
                   97,
                   121,
                   101,
-                  114
+                  114,
+                  95,
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  115,
+                  108,
+                  111,
+                  116
                 ]
               },
               {
                 "kind": "account",
                 "path": "player"
+              },
+              {
+                "kind": "arg",
+                "path": "tier"
               }
             ]
           }
@@ -757,9 +762,8 @@ export type Cookie = {
         {
           "name": "plugin",
           "docs": [
-            "Plugin to install"
+            "Plugin currently installed in the slot"
           ],
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -775,51 +779,26 @@ export type Cookie = {
               },
               {
                 "kind": "account",
-                "path": "plugin.plugin_id",
-                "account": "plugin"
+                "path": "player_plugin_slot.plugin_id",
+                "account": "playerPluginSlot"
               }
             ]
           }
         },
         {
-          "name": "playerPluginSlot",
-          "docs": [
-            "Player's plugin slot for this tier"
-          ],
-          "writable": true,
+          "name": "gameConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
-                  97,
-                  121,
-                  101,
-                  114,
-                  95,
-                  112,
-                  108,
-                  117,
-                  103,
-                  105,
-                  110,
-                  95,
-                  115,
-                  108,
+                  99,
                   111,
-                  116
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "player"
-              },
-              {
-                "kind": "account",
-                "path": "plugin.tier",
-                "account": "plugin"
               }
             ]
           }
@@ -827,7 +806,7 @@ export type Cookie = {
         {
           "name": "playerTokenAccount",
           "docs": [
-            "Player's cookie token account (to pay install cost)"
+            "Player's cookie token account (to receive cookies)"
           ],
           "writable": true,
           "pda": {
@@ -917,100 +896,6 @@ export type Cookie = {
             }
           }
         },
-        {
-          "name": "creatorTokenAccount",
-          "docs": [
-            "Creator's cookie token account (to receive revenue share)"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "path": "plugin.creator",
-                "account": "plugin"
-              },
-              {
-                "kind": "const",
-                "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "cookieMint"
-              }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
-          }
-        },
         {
           "name": "cookieMint",
           "writable": true,
@@ -1033,33 +918,35 @@ export type Cookie = {
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "tier",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "onClick",
+      "name": "closePlayer",
       "discriminator": [
-        161,
-        191,
-        8,
-        211,
-        178,
-        196,
-        182,
-        220
+        225,
+        227,
+        204,
+        63,
+        32,
+        122,
+        58,
+        227
       ],
       "accounts": [
         {
-          "name": "sessionToken",
-          "optional": true
+          "name": "player",
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "player",
+          "name": "playerData",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1076,29 +963,48 @@ export type Cookie = {
               },
               {
                 "kind": "account",
-                "path": "player.authority",
-                "account": "playerData"
+                "path": "player"
               }
             ]
           }
         },
         {
-          "name": "signer",
-          "writable": true,
-          "signer": true
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
         },
         {
-          "name": "playerAuthority",
-          "writable": true
+          "name": "nameRegistry",
+          "docs": [
+            "Registry entry for the player's name, required if a name is set"
+          ],
+          "writable": true,
+          "optional": true
         },
         {
           "name": "playerTokenAccount",
+          "docs": [
+            "Player's cookie token account (to receive pending cookies)"
+          ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "playerAuthority"
+                "path": "player"
               },
               {
                 "kind": "const",
@@ -1139,7 +1045,7 @@ export type Cookie = {
               },
               {
                 "kind": "account",
-                "path": "rewardTokenMint"
+                "path": "cookieMint"
               }
             ],
             "program": {
@@ -1182,7 +1088,7 @@ export type Cookie = {
           }
         },
         {
-          "name": "rewardTokenMint",
+          "name": "cookieMint",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1203,44 +1109,30 @@ export type Cookie = {
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        },
-        {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
       "args": []
     },
     {
-      "name": "uninstallPlugin",
+      "name": "craft",
       "discriminator": [
-        99,
-        185,
-        228,
-        160,
-        167,
-        137,
-        140,
-        174
+        161,
+        233,
+        177,
+        214,
+        243,
+        109,
+        161,
+        224
       ],
       "accounts": [
         {
           "name": "player",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "playerPluginSlot"
-          ]
+          "signer": true
         },
         {
-          "name": "playerPluginSlot",
-          "docs": [
-            "Player's plugin slot for this tier"
-          ],
+          "name": "playerData",
           "writable": true,
           "pda": {
             "seeds": [
@@ -1252,19 +1144,73 @@ export type Cookie = {
                   97,
                   121,
                   101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipe",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
                   114,
-                  95,
+                  101,
+                  99,
+                  105,
                   112,
-                  108,
-                  117,
-                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "recipeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerItem",
+          "docs": [
+            "Only needed for recipes that output an item"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
                   105,
-                  110,
-                  95,
-                  115,
-                  108,
-                  111,
-                  116
+                  116,
+                  101,
+                  109
                 ]
               },
               {
@@ -1272,16 +1218,17 @@ export type Cookie = {
                 "path": "player"
               },
               {
-                "kind": "arg",
-                "path": "tier"
+                "kind": "account",
+                "path": "recipe.output",
+                "account": "recipe"
               }
             ]
           }
         },
         {
-          "name": "playerTokenAccount",
+          "name": "playerWoodAccount",
           "docs": [
-            "Player's cookie token account (to receive final cookies)"
+            "Player's wood token account (to burn the wood cost)"
           ],
           "writable": true,
           "pda": {
@@ -1329,7 +1276,7 @@ export type Cookie = {
               },
               {
                 "kind": "account",
-                "path": "cookieMint"
+                "path": "woodMint"
               }
             ],
             "program": {
@@ -1372,18 +1319,16 @@ export type Cookie = {
           }
         },
         {
-          "name": "cookieMint",
+          "name": "woodMint",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  114,
-                  101,
                   119,
-                  97,
-                  114,
+                  111,
+                  111,
                   100
                 ]
               }
@@ -1391,62 +1336,9 @@ export type Cookie = {
           }
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": [
-        {
-          "name": "tier",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "unlockTier",
-      "discriminator": [
-        123,
-        100,
-        192,
-        160,
-        182,
-        237,
-        184,
-        152
-      ],
-      "accounts": [
-        {
-          "name": "player",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "playerData",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  112,
-                  108,
-                  97,
-                  121,
-                  101,
-                  114
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "player"
-              }
-            ]
-          }
-        },
-        {
-          "name": "playerTokenAccount",
+          "name": "playerCookieAccount",
           "docs": [
-            "Player's cookie token account (to burn unlock cost)"
+            "Player's cookie token account (to burn the cookie cost)"
           ],
           "writable": true,
           "pda": {
@@ -1558,256 +1450,5518 @@ export type Cookie = {
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
         }
       ],
       "args": [
         {
-          "name": "tier",
-          "type": "u8"
+          "name": "recipeId",
+          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "gameData",
-      "discriminator": [
-        237,
-        88,
-        58,
-        243,
-        16,
-        69,
-        238,
-        190
-      ]
-    },
-    {
-      "name": "playerData",
-      "discriminator": [
-        197,
-        65,
-        216,
-        202,
-        43,
-        139,
-        147,
-        128
-      ]
     },
     {
-      "name": "playerPluginSlot",
+      "name": "createMint",
       "discriminator": [
-        248,
-        102,
+        69,
+        44,
+        215,
+        132,
         253,
-        138,
-        111,
-        187,
-        11,
-        128
-      ]
-    },
-    {
-      "name": "plugin",
-      "discriminator": [
-        3,
-        199,
-        88,
-        224,
-        120,
-        206,
-        239,
-        225
-      ]
-    },
-    {
-      "name": "sessionToken",
-      "discriminator": [
-        233,
-        4,
-        115,
-        14,
-        46,
-        21,
-        1,
-        15
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "notEnoughEnergy",
-      "msg": "Not enough energy"
-    },
-    {
-      "code": 6001,
-      "name": "wrongAuthority",
-      "msg": "Wrong Authority"
-    },
-    {
-      "code": 6002,
-      "name": "notEnoughHealth",
-      "msg": "Not enough health"
-    },
-    {
-      "code": 6003,
-      "name": "invalidTier",
-      "msg": "Invalid tier (must be 1-10)"
-    },
-    {
+        214,
+        41,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "The admin account that can create the mint"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardTokenMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadataAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "const",
+                "value": [
+                  11,
+                  112,
+                  101,
+                  177,
+                  227,
+                  209,
+                  124,
+                  69,
+                  56,
+                  157,
+                  82,
+                  127,
+                  107,
+                  4,
+                  195,
+                  205,
+                  88,
+                  184,
+                  108,
+                  115,
+                  26,
+                  160,
+                  253,
+                  181,
+                  73,
+                  182,
+                  209,
+                  188,
+                  3,
+                  248,
+                  41,
+                  70
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "rewardTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                11,
+                112,
+                101,
+                177,
+                227,
+                209,
+                124,
+                69,
+                56,
+                157,
+                82,
+                127,
+                107,
+                4,
+                195,
+                205,
+                88,
+                184,
+                108,
+                115,
+                26,
+                160,
+                253,
+                181,
+                73,
+                182,
+                209,
+                188,
+                3,
+                248,
+                41,
+                70
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "createPlugin",
+      "discriminator": [
+        210,
+        213,
+        78,
+        20,
+        110,
+        209,
+        221,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerData",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pluginRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "plugin",
+          "docs": [
+            "Plugin account - PDA seeded by the registry's next plugin_id"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin_registry.next_plugin_id",
+                "account": "pluginRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorTokenAccount",
+          "docs": [
+            "Creator's cookie token account (to burn creation cost)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "cookieMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "cookieMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pluginNftMint",
+          "docs": [
+            "Mint of the plugin's NFT, only passed to mint one"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin_registry.next_plugin_id",
+                "account": "pluginRegistry"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creatorNftAccount",
+          "docs": [
+            "Creator's account for the plugin NFT"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "pluginNftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "nftMetadata",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "nftMasterEdition",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "optional": true,
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "optional": true,
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "optional": true,
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "tier",
+          "type": "u8"
+        },
+        {
+          "name": "metadataUri",
+          "type": "string"
+        },
+        {
+          "name": "creatorShareBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "createRecipe",
+      "discriminator": [
+        64,
+        139,
+        143,
+        169,
+        196,
+        212,
+        15,
+        56
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipe",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  105,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "recipeId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "recipeId",
+          "type": "u64"
+        },
+        {
+          "name": "woodCost",
+          "type": "u64"
+        },
+        {
+          "name": "cookieCost",
+          "type": "u64"
+        },
+        {
+          "name": "output",
+          "type": {
+            "defined": {
+              "name": "recipeOutput"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "createWoodMint",
+      "discriminator": [
+        125,
+        209,
+        178,
+        83,
+        166,
+        89,
+        218,
+        191
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "The admin account that can create the mint"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "woodMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  111,
+                  111,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadataAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "const",
+                "value": [
+                  11,
+                  112,
+                  101,
+                  177,
+                  227,
+                  209,
+                  124,
+                  69,
+                  56,
+                  157,
+                  82,
+                  127,
+                  107,
+                  4,
+                  195,
+                  205,
+                  88,
+                  184,
+                  108,
+                  115,
+                  26,
+                  160,
+                  253,
+                  181,
+                  73,
+                  182,
+                  209,
+                  188,
+                  3,
+                  248,
+                  41,
+                  70
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "woodMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                11,
+                112,
+                101,
+                177,
+                227,
+                209,
+                124,
+                69,
+                56,
+                157,
+                82,
+                127,
+                107,
+                4,
+                195,
+                205,
+                88,
+                184,
+                108,
+                115,
+                26,
+                160,
+                253,
+                181,
+                73,
+                182,
+                209,
+                188,
+                3,
+                248,
+                41,
+                70
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rent",
+          "address": "SysvarRent111111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initAdmin",
+      "discriminator": [
+        97,
+        65,
+        97,
+        27,
+        200,
+        206,
+        72,
+        219
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "H9BK2gP55dKbadkAwroaTZo5L5vw3QuDtSLmE6WbWKE9"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initConfig",
+      "discriminator": [
+        23,
+        235,
+        115,
+        232,
+        168,
+        96,
+        1,
+        231
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "docs": [
+            "Singleton config account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initPlayer",
+      "discriminator": [
+        114,
+        27,
+        219,
+        144,
+        50,
+        15,
+        228,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "signer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameData",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "arg",
+                "path": "levelSeed"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "levelSeed",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "initPluginRegistry",
+      "discriminator": [
+        190,
+        72,
+        78,
+        241,
+        102,
+        29,
+        198,
+        187
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pluginRegistry",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "nextPluginId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "installPlugin",
+      "discriminator": [
+        136,
+        37,
+        116,
+        68,
+        189,
+        124,
+        6,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerData",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "plugin",
+          "docs": [
+            "Plugin to install"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin.plugin_id",
+                "account": "plugin"
+              }
+            ]
+          }
+        },
+        {
+          "name": "previousPlugin",
+          "docs": [
+            "Plugin currently in the slot, required when replacing a different plugin"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "previous_plugin.plugin_id",
+                "account": "plugin"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerPluginSlot",
+          "docs": [
+            "Player's plugin slot for this tier"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "account",
+                "path": "plugin.tier",
+                "account": "plugin"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pluginRating",
+          "docs": [
+            "Player's rating of the plugin, created unrated on first install"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "docs": [
+            "Player's cookie token account (to pay install cost)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "cookieMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "creatorTokenAccount",
+          "docs": [
+            "Cookie token account of the revenue recipient: the plugin NFT holder,",
+            "or the creator if the plugin has no NFT"
+          ],
+          "writable": true
+        },
+        {
+          "name": "nftHolderAccount",
+          "docs": [
+            "Token account holding the plugin NFT, required if the plugin has one"
+          ],
+          "optional": true
+        },
+        {
+          "name": "cookieMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateAccount",
+      "discriminator": [
+        177,
+        228,
+        60,
+        125,
+        13,
+        116,
+        44,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "account",
+          "writable": true
+        },
+        {
+          "name": "programAdmin",
+          "docs": [
+            "Only needed to migrate GameData, or a plugin on the creator's behalf"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "plugin",
+          "docs": [
+            "Only needed to migrate a slot with a plugin installed: that plugin"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin.plugin_id",
+                "account": "plugin"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerData",
+          "docs": [
+            "Only needed to migrate a slot with a plugin installed: the player's data"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "docs": [
+            "Only needed to migrate a slot with a plugin installed"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pluginRating",
+          "docs": [
+            "Only needed to migrate a slot with a plugin installed: the player's",
+            "rating of that plugin, created unrated if missing"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin"
+              },
+              {
+                "kind": "account",
+                "path": "authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateWood",
+      "discriminator": [
+        227,
+        188,
+        96,
+        76,
+        142,
+        45,
+        195,
+        184
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player.authority",
+                "account": "playerData"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerAuthority"
+        },
+        {
+          "name": "playerWoodAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "playerAuthority"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "woodMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "woodMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  111,
+                  111,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "moderatePlugin",
+      "discriminator": [
+        174,
+        78,
+        26,
+        224,
+        149,
+        49,
+        85,
+        109
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "plugin",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin.plugin_id",
+                "account": "plugin"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "status",
+          "type": {
+            "defined": {
+              "name": "pluginStatus"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "onClick",
+      "discriminator": [
+        161,
+        191,
+        8,
+        211,
+        178,
+        196,
+        182,
+        220
+      ],
+      "accounts": [
+        {
+          "name": "sessionToken",
+          "optional": true
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player.authority",
+                "account": "playerData"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerAuthority",
+          "writable": true
+        },
+        {
+          "name": "playerTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "playerAuthority"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "rewardTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "rewardTokenMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "counter",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "onClickBatch",
+      "discriminator": [
+        162,
+        141,
+        92,
+        177,
+        104,
+        92,
+        123,
+        59
+      ],
+      "accounts": [
+        {
+          "name": "sessionToken",
+          "optional": true
+        },
+        {
+          "name": "player",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player.authority",
+                "account": "playerData"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "signer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerAuthority",
+          "writable": true
+        },
+        {
+          "name": "playerTokenAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "playerAuthority"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "rewardTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "rewardTokenMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructions",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "count",
+          "type": "u16"
+        },
+        {
+          "name": "counter",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAuthority",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "ratePlugin",
+      "discriminator": [
+        38,
+        28,
+        32,
+        188,
+        69,
+        43,
+        135,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "signer": true
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "plugin",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin.plugin_id",
+                "account": "plugin"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pluginRating",
+          "docs": [
+            "Created by install_plugin, so only players who installed the plugin",
+            "have one"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  114,
+                  97,
+                  116,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin"
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftHolderAccount",
+          "docs": [
+            "Token account holding the plugin NFT, required if the plugin has one"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rating",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setPaused",
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "flags",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setPlayerName",
+      "discriminator": [
+        69,
+        49,
+        60,
+        55,
+        235,
+        176,
+        232,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerData",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nameRegistry",
+          "docs": [
+            "Registry entry for the new name"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  110,
+                  97,
+                  109,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "name"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oldNameRegistry",
+          "docs": [
+            "Registry entry for the current name, required when renaming"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "setPluginListing",
+      "discriminator": [
+        75,
+        105,
+        157,
+        86,
+        197,
+        157,
+        10,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "plugin",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin.plugin_id",
+                "account": "plugin"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftHolderAccount",
+          "docs": [
+            "Token account holding the plugin NFT, required if the plugin has one"
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "listed",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "uninstallPlugin",
+      "discriminator": [
+        99,
+        185,
+        228,
+        160,
+        167,
+        137,
+        140,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "playerPluginSlot"
+          ]
+        },
+        {
+          "name": "playerData",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerPluginSlot",
+          "docs": [
+            "Player's plugin slot for this tier"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "arg",
+                "path": "tier"
+              }
+            ]
+          }
+        },
+        {
+          "name": "plugin",
+          "docs": [
+            "Plugin currently installed in the slot"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player_plugin_slot.plugin_id",
+                "account": "playerPluginSlot"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "docs": [
+            "Player's cookie token account (to receive final cookies)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "cookieMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "cookieMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "tier",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "unlockTier",
+      "discriminator": [
+        123,
+        100,
+        192,
+        160,
+        182,
+        237,
+        184,
+        152
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerData",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "docs": [
+            "Player's cookie token account (to burn unlock cost)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "cookieMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "cookieMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": [
+        {
+          "name": "tier",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "updateConfigParams"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMintMetadata",
+      "discriminator": [
+        46,
+        244,
+        2,
+        123,
+        67,
+        219,
+        22,
+        121
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardTokenMint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "metadataAccount",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  101,
+                  116,
+                  97,
+                  100,
+                  97,
+                  116,
+                  97
+                ]
+              },
+              {
+                "kind": "const",
+                "value": [
+                  11,
+                  112,
+                  101,
+                  177,
+                  227,
+                  209,
+                  124,
+                  69,
+                  56,
+                  157,
+                  82,
+                  127,
+                  107,
+                  4,
+                  195,
+                  205,
+                  88,
+                  184,
+                  108,
+                  115,
+                  26,
+                  160,
+                  253,
+                  181,
+                  73,
+                  182,
+                  209,
+                  188,
+                  3,
+                  248,
+                  41,
+                  70
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "rewardTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                11,
+                112,
+                101,
+                177,
+                227,
+                209,
+                124,
+                69,
+                56,
+                157,
+                82,
+                127,
+                107,
+                4,
+                195,
+                205,
+                88,
+                184,
+                108,
+                115,
+                26,
+                160,
+                253,
+                181,
+                73,
+                182,
+                209,
+                188,
+                3,
+                248,
+                41,
+                70
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        }
+      ],
+      "args": [
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "makeImmutable",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updatePlugin",
+      "discriminator": [
+        18,
+        125,
+        28,
+        53,
+        149,
+        126,
+        226,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "plugin",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin.plugin_id",
+                "account": "plugin"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftHolderAccount",
+          "docs": [
+            "Token account holding the plugin NFT, required if the plugin has one"
+          ],
+          "optional": true
+        },
+        {
+          "name": "pluginNftMint",
+          "docs": [
+            "The plugin NFT's mint, required to change the URI of a plugin with one"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  117,
+                  103,
+                  105,
+                  110,
+                  95,
+                  110,
+                  102,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "plugin.plugin_id",
+                "account": "plugin"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMetadata",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "optional": true,
+          "address": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        }
+      ],
+      "args": [
+        {
+          "name": "metadataUri",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "creatorShareBps",
+          "type": {
+            "option": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRecipe",
+      "discriminator": [
+        79,
+        118,
+        143,
+        237,
+        68,
+        36,
+        242,
+        15
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "programAdmin",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipe",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  105,
+                  112,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "recipe.recipe_id",
+                "account": "recipe"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "woodCost",
+          "type": "u64"
+        },
+        {
+          "name": "cookieCost",
+          "type": "u64"
+        },
+        {
+          "name": "output",
+          "type": {
+            "defined": {
+              "name": "recipeOutput"
+            }
+          }
+        },
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "upgradeAxe",
+      "discriminator": [
+        112,
+        80,
+        85,
+        193,
+        79,
+        23,
+        60,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerData",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerTokenAccount",
+          "docs": [
+            "Player's cookie token account (to burn upgrade cost)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "player"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "cookieMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "cookieMint",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  119,
+                  97,
+                  114,
+                  100
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "gameConfig",
+      "discriminator": [
+        45,
+        146,
+        146,
+        33,
+        170,
+        69,
+        96,
+        133
+      ]
+    },
+    {
+      "name": "gameData",
+      "discriminator": [
+        237,
+        88,
+        58,
+        243,
+        16,
+        69,
+        238,
+        190
+      ]
+    },
+    {
+      "name": "goldenCookie",
+      "discriminator": [
+        133,
+        35,
+        67,
+        153,
+        156,
+        90,
+        49,
+        90
+      ]
+    },
+    {
+      "name": "playerData",
+      "discriminator": [
+        197,
+        65,
+        216,
+        202,
+        43,
+        139,
+        147,
+        128
+      ]
+    },
+    {
+      "name": "playerItem",
+      "discriminator": [
+        66,
+        167,
+        241,
+        200,
+        146,
+        107,
+        101,
+        121
+      ]
+    },
+    {
+      "name": "playerName",
+      "discriminator": [
+        102,
+        9,
+        241,
+        98,
+        96,
+        196,
+        212,
+        161
+      ]
+    },
+    {
+      "name": "playerPluginSlot",
+      "discriminator": [
+        248,
+        102,
+        253,
+        138,
+        111,
+        187,
+        11,
+        128
+      ]
+    },
+    {
+      "name": "plugin",
+      "discriminator": [
+        3,
+        199,
+        88,
+        224,
+        120,
+        206,
+        239,
+        225
+      ]
+    },
+    {
+      "name": "pluginRating",
+      "discriminator": [
+        26,
+        50,
+        198,
+        160,
+        98,
+        160,
+        237,
+        86
+      ]
+    },
+    {
+      "name": "pluginRegistry",
+      "discriminator": [
+        23,
+        194,
+        178,
+        221,
+        218,
+        51,
+        103,
+        100
+      ]
+    },
+    {
+      "name": "programAdmin",
+      "discriminator": [
+        93,
+        130,
+        19,
+        193,
+        29,
+        34,
+        250,
+        140
+      ]
+    },
+    {
+      "name": "recipe",
+      "discriminator": [
+        10,
+        162,
+        156,
+        100,
+        56,
+        193,
+        205,
+        77
+      ]
+    },
+    {
+      "name": "sessionToken",
+      "discriminator": [
+        233,
+        4,
+        115,
+        14,
+        46,
+        21,
+        1,
+        15
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "clickEvent",
+      "discriminator": [
+        189,
+        122,
+        71,
+        227,
+        229,
+        69,
+        37,
+        47
+      ]
+    },
+    {
+      "name": "dailyRewardClaimed",
+      "discriminator": [
+        148,
+        21,
+        255,
+        155,
+        242,
+        125,
+        124,
+        57
+      ]
+    },
+    {
+      "name": "goldenCookieClaimed",
+      "discriminator": [
+        5,
+        63,
+        132,
+        80,
+        201,
+        191,
+        217,
+        133
+      ]
+    },
+    {
+      "name": "itemCrafted",
+      "discriminator": [
+        19,
+        69,
+        109,
+        223,
+        116,
+        34,
+        30,
+        27
+      ]
+    },
+    {
+      "name": "pluginRated",
+      "discriminator": [
+        243,
+        107,
+        8,
+        249,
+        222,
+        142,
+        59,
+        17
+      ]
+    },
+    {
+      "name": "pluginStatusChanged",
+      "discriminator": [
+        14,
+        104,
+        12,
+        239,
+        216,
+        105,
+        31,
+        121
+      ]
+    },
+    {
+      "name": "pluginUpdated",
+      "discriminator": [
+        207,
+        111,
+        61,
+        84,
+        46,
+        75,
+        244,
+        77
+      ]
+    },
+    {
+      "name": "treeFelled",
+      "discriminator": [
+        72,
+        192,
+        107,
+        163,
+        172,
+        114,
+        176,
+        43
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "notEnoughEnergy",
+      "msg": "Not enough energy"
+    },
+    {
+      "code": 6001,
+      "name": "wrongAuthority",
+      "msg": "Wrong Authority"
+    },
+    {
+      "code": 6002,
+      "name": "notEnoughHealth",
+      "msg": "Not enough health"
+    },
+    {
+      "code": 6003,
+      "name": "invalidTier",
+      "msg": "Invalid tier (must be 1-10)"
+    },
+    {
       "code": 6004,
       "name": "metadataUriTooLong",
       "msg": "Metadata URI too long (max 200 characters)"
     },
     {
-      "code": 6005,
-      "name": "invalidRevenueShare",
-      "msg": "Invalid revenue share (must be <= 100%)"
+      "code": 6005,
+      "name": "invalidRevenueShare",
+      "msg": "Invalid revenue share (must be <= 100%)"
+    },
+    {
+      "code": 6006,
+      "name": "tierNotUnlocked",
+      "msg": "Tier not unlocked"
+    },
+    {
+      "code": 6007,
+      "name": "tierAlreadyUnlocked",
+      "msg": "Tier already unlocked"
+    },
+    {
+      "code": 6008,
+      "name": "noPluginInstalled",
+      "msg": "No plugin installed in this slot"
+    },
+    {
+      "code": 6009,
+      "name": "nothingToClaim",
+      "msg": "Nothing to claim yet"
+    },
+    {
+      "code": 6010,
+      "name": "overflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6011,
+      "name": "missingPluginAccount",
+      "msg": "Plugin account for the occupied slot is missing"
+    },
+    {
+      "code": 6012,
+      "name": "pluginHasActiveInstalls",
+      "msg": "A plugin created by this player still has active installs"
+    },
+    {
+      "code": 6013,
+      "name": "pluginNotActive",
+      "msg": "Plugin is not listed for install"
+    },
+    {
+      "code": 6014,
+      "name": "pluginModerated",
+      "msg": "Plugin status was set by a moderator"
+    },
+    {
+      "code": 6015,
+      "name": "pluginBanned",
+      "msg": "Plugin is banned"
+    },
+    {
+      "code": 6016,
+      "name": "invalidRating",
+      "msg": "Rating must be between 1 and 5"
+    },
+    {
+      "code": 6017,
+      "name": "pluginNotUsed",
+      "msg": "Only players who installed this plugin can rate it"
+    },
+    {
+      "code": 6018,
+      "name": "missingRatingAccount",
+      "msg": "All of the player's plugin ratings must be passed"
+    },
+    {
+      "code": 6019,
+      "name": "cannotRateOwnPlugin",
+      "msg": "Creators can't rate their own plugins"
+    },
+    {
+      "code": 6020,
+      "name": "missingNftAccount",
+      "msg": "All plugin NFT accounts must be passed together"
+    },
+    {
+      "code": 6021,
+      "name": "wrongRevenueRecipient",
+      "msg": "Revenue must go to the plugin NFT holder"
+    },
+    {
+      "code": 6022,
+      "name": "notNftHolder",
+      "msg": "Token account doesn't hold the plugin NFT"
+    },
+    {
+      "code": 6023,
+      "name": "invalidConfig",
+      "msg": "Invalid game config"
+    },
+    {
+      "code": 6024,
+      "name": "unauthorized",
+      "msg": "Signer is not the admin"
+    },
+    {
+      "code": 6025,
+      "name": "gamePaused",
+      "msg": "This part of the game is paused"
+    },
+    {
+      "code": 6026,
+      "name": "invalidAccount",
+      "msg": "Account is not a migratable game account"
+    },
+    {
+      "code": 6027,
+      "name": "alreadyMigrated",
+      "msg": "Account is already on the current layout"
+    },
+    {
+      "code": 6028,
+      "name": "accountNotMigrated",
+      "msg": "Account has an old layout, run migrate_account first"
+    },
+    {
+      "code": 6029,
+      "name": "noWoodToMigrate",
+      "msg": "No wood left to convert into tokens"
+    },
+    {
+      "code": 6030,
+      "name": "clickRateExceeded",
+      "msg": "Too many clicks, slow down"
+    },
+    {
+      "code": 6031,
+      "name": "cpiNotAllowed",
+      "msg": "Clicking is not allowed through CPI"
+    },
+    {
+      "code": 6032,
+      "name": "dailyRewardAlreadyClaimed",
+      "msg": "Daily reward already claimed today"
+    },
+    {
+      "code": 6033,
+      "name": "invalidRecipe",
+      "msg": "Invalid recipe"
+    },
+    {
+      "code": 6034,
+      "name": "recipeDisabled",
+      "msg": "This recipe is disabled"
+    },
+    {
+      "code": 6035,
+      "name": "missingItemAccount",
+      "msg": "Item recipes need the player item account"
+    },
+    {
+      "code": 6036,
+      "name": "noGoldenCookie",
+      "msg": "No golden cookie is live right now"
+    },
+    {
+      "code": 6037,
+      "name": "goldenCookieClaimed",
+      "msg": "This golden cookie was already claimed"
+    },
+    {
+      "code": 6038,
+      "name": "invalidNameLength",
+      "msg": "Name must be 3-32 characters"
+    },
+    {
+      "code": 6039,
+      "name": "invalidNameCharacters",
+      "msg": "Name may only contain letters, digits, '_' and '-'"
+    },
+    {
+      "code": 6040,
+      "name": "nameTaken",
+      "msg": "Name is already taken"
+    },
+    {
+      "code": 6041,
+      "name": "oldNameRequired",
+      "msg": "The current name registry account must be passed when renaming"
+    },
+    {
+      "code": 6042,
+      "name": "levelTooLow",
+      "msg": "Level too low for this tier"
+    },
+    {
+      "code": 6043,
+      "name": "invalidClickCount",
+      "msg": "Click batch is empty or larger than the click rate limit"
+    },
+    {
+      "code": 6044,
+      "name": "invalidChopCount",
+      "msg": "Chop count must be at least 1"
+    },
+    {
+      "code": 6045,
+      "name": "maxAxeLevel",
+      "msg": "Axe is already at the max level"
+    },
+    {
+      "code": 6046,
+      "name": "staleNonce",
+      "msg": "Counter must be greater than the last one used"
+    },
+    {
+      "code": 6047,
+      "name": "missingSlotAccount",
+      "msg": "Every occupied plugin slot must be passed"
+    }
+  ],
+  "types": [
+    {
+      "name": "clickEvent",
+      "docs": [
+        "Emitted on every `on_click` / `on_click_batch` so clients can show combo effects"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "clicks",
+            "docs": [
+              "Clicks in this transaction"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cookies",
+            "docs": [
+              "Cookies minted in this transaction"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "combo",
+            "docs": [
+              "Current combo after these clicks"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "multiplierBps",
+            "docs": [
+              "Reward multiplier applied, in basis points (10000 = 1x)"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dailyRewardClaimed",
+      "docs": [
+        "Emitted when a player claims their daily reward"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "streak",
+            "docs": [
+              "Consecutive days claimed, including today"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "cookies",
+            "docs": [
+              "Cookies minted"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gameConfig",
+      "docs": [
+        "Global game configuration - singleton PDA seeded by \"config\"",
+        "Holds every economic knob so the game can be retuned without a redeploy"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "baseProductionRate",
+            "docs": [
+              "Tier 1 plugin production in cookies per hour"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "creationBaseCost",
+            "docs": [
+              "Base cost to create a plugin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "installBaseCost",
+            "docs": [
+              "Base cost to install a plugin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unlockBaseCost",
+            "docs": [
+              "Base cost to unlock a tier"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "creatorShareBps",
+            "docs": [
+              "Default revenue share to creator in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "burnShareBps",
+            "docs": [
+              "Default revenue share burned in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "maxEnergy",
+            "docs": [
+              "Maximum energy a player can hold"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timeToRefillEnergy",
+            "docs": [
+              "Seconds it takes to refill one energy"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "clickEnergyCost",
+            "docs": [
+              "Energy spent per cookie click"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Bitmask of paused instruction families (see `PAUSE_*` constants)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "xpPerClick",
+            "docs": [
+              "XP awarded per cookie click"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "xpPerChop",
+            "docs": [
+              "XP awarded per chop"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "xpPerClaim",
+            "docs": [
+              "XP awarded per plugin cookie claim"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "levelXpBase",
+            "docs": [
+              "XP needed for level 2; level N needs `level_xp_base * (N - 1)^2`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxLevel",
+            "docs": [
+              "Highest reachable level"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "levelsPerTier",
+            "docs": [
+              "Levels needed per plugin tier (tier N needs level `1 + (N - 1) * levels_per_tier`)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "maxEnergyPerLevel",
+            "docs": [
+              "Extra max energy per level above 1"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "clickPowerPerTier",
+            "docs": [
+              "Click power added per tier of every installed plugin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "chopEnergyCost",
+            "docs": [
+              "Energy spent per chop"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "woodPerAxeLevel",
+            "docs": [
+              "Extra wood per chop for each axe level"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "axeUpgradeBaseCost",
+            "docs": [
+              "Cookie cost of the first axe upgrade, doubling for each level after"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxAxeLevel",
+            "docs": [
+              "Highest axe level"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "comboWindowSeconds",
+            "docs": [
+              "Seconds between clicks before the combo resets"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "comboStepClicks",
+            "docs": [
+              "Combo clicks per multiplier step"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "comboStepBonusBps",
+            "docs": [
+              "Multiplier added per combo step in basis points"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxComboMultiplierBps",
+            "docs": [
+              "Highest combo multiplier in basis points (10000 = 1x)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "clickRateWindowSlots",
+            "docs": [
+              "Length of the click rate limit window in slots"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxClicksPerWindow",
+            "docs": [
+              "Most clicks a player can land in one rate limit window"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "goldenCookieIntervalSlots",
+            "docs": [
+              "Slots between golden cookie spawns"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "goldenCookieLifetimeSlots",
+            "docs": [
+              "Slots a golden cookie stays claimable after it spawns"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "goldenCookieLumpSum",
+            "docs": [
+              "Cookies paid out by a lump sum golden cookie"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "goldenCookieFrenzyChance",
+            "docs": [
+              "Chance (0-100) that a golden cookie is a frenzy instead of a lump sum"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "frenzyDurationSeconds",
+            "docs": [
+              "How long a frenzy lasts in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "frenzyMultiplierBps",
+            "docs": [
+              "Production multiplier during a frenzy in basis points (10000 = 1x)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "dailyRewardBase",
+            "docs": [
+              "Cookies for the first day of a daily reward streak"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "dailyRewardPerStreakDay",
+            "docs": [
+              "Extra cookies per consecutive day of the streak"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxDailyStreakBonusDays",
+            "docs": [
+              "Streak length after which the daily reward stops growing"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "treeHp",
+            "docs": [
+              "Wood in each tree before it falls"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fellingBonusWood",
+            "docs": [
+              "Bonus wood for the player who fells a tree"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "shareChangeNoticeSeconds",
+            "docs": [
+              "Seconds before a creator's new revenue share takes effect"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "gameData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "totalWoodCollected",
+            "docs": [
+              "Wood chopped on this level over all trees"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treeIndex",
+            "docs": [
+              "Number of the tree currently being chopped (0 for the first tree)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "treeHp",
+            "docs": [
+              "Wood left in the current tree, it falls at 0"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastFeller",
+            "docs": [
+              "Player who delivered the final chop on the last felled tree"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goldenCookie",
+      "docs": [
+        "Golden cookie tracker - singleton PDA seeded by \"golden_cookie\"",
+        "A golden cookie spawns every `golden_cookie_interval_slots` (see",
+        "`GameConfig::golden_cookie_round`); this account only remembers which spawn",
+        "was claimed last so each one pays out once"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lastClaimedRound",
+            "docs": [
+              "Spawn number of the last claimed golden cookie"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastClaimedBy",
+            "docs": [
+              "Player who claimed it"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalClaims",
+            "docs": [
+              "Number of golden cookies claimed so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "goldenCookieClaimed",
+      "docs": [
+        "Emitted when a player claims a golden cookie"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "round",
+            "docs": [
+              "Spawn number of the claimed golden cookie"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "frenzy",
+            "docs": [
+              "Whether the reward was a frenzy (otherwise a lump sum)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "cookies",
+            "docs": [
+              "Cookies minted (0 for a frenzy)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "frenzyEnd",
+            "docs": [
+              "When the frenzy ends (0 for a lump sum)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "itemCrafted",
+      "docs": [
+        "Emitted when a player crafts a recipe"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "recipeId",
+            "type": "u64"
+          },
+          {
+            "name": "output",
+            "docs": [
+              "What the player got"
+            ],
+            "type": {
+              "defined": {
+                "name": "recipeOutput"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version, bumped when fields are added (see migrate_account)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "xp",
+            "type": "u64"
+          },
+          {
+            "name": "wood",
+            "docs": [
+              "Wood chopped before wood became a token, see `migrate_wood`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "energy",
+            "type": "u64"
+          },
+          {
+            "name": "lastLogin",
+            "type": "i64"
+          },
+          {
+            "name": "lastId",
+            "docs": [
+              "Last nonce used by `chop_tree` / `on_click`, see `use_nonce`"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "unlockedTiers",
+            "docs": [
+              "Bitmask for unlocked tiers (bits 0-9 for tiers 1-10)",
+              "Tier 1 is unlocked by default (bit 0 = 1)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "pluginGlobalCounter",
+            "docs": [
+              "Number of plugins this player created, plus one. Plugin IDs come from",
+              "the global `PluginRegistry`; this used to be a per-player ID counter."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalClicks",
+            "docs": [
+              "Lifetime number of cookie clicks"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "installedTiers",
+            "docs": [
+              "Bitmask of tiers with a plugin installed (bits 0-9 for tiers 1-10)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "clickPower",
+            "docs": [
+              "Cookies minted per click, cached from `installed_tiers`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "combo",
+            "docs": [
+              "Consecutive clicks within the combo window"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastClickAt",
+            "docs": [
+              "Timestamp of the last click (for the combo window)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "frenzyStart",
+            "docs": [
+              "Start of the current (or last) golden cookie frenzy or crafted boost"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "frenzyEnd",
+            "docs": [
+              "End of the current (or last) golden cookie frenzy"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "frenzyMultiplierBps",
+            "docs": [
+              "Production multiplier of that frenzy in basis points"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "clickWindowStart",
+            "docs": [
+              "First slot of the current click rate limit window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "clicksInWindow",
+            "docs": [
+              "Clicks landed in the current click rate limit window"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "dailyStreak",
+            "docs": [
+              "Consecutive UTC days the daily reward was claimed"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "lastDailyClaimDay",
+            "docs": [
+              "UTC day number (days since the unix epoch) of the last daily reward"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "axeLevel",
+            "docs": [
+              "Axe upgrade level (0 = starter axe), raises wood per chop"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "usedPlugins",
+            "docs": [
+              "Distinct plugins this player has installed, one `PluginRating` each"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "tierNotUnlocked",
-      "msg": "Tier not unlocked"
+      "name": "playerItem",
+      "docs": [
+        "Crafted items a player owns of one kind - PDA seeded by \"item\", player and item_id"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "Player who owns the items"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "itemId",
+            "docs": [
+              "Item kind (see `RecipeOutput::Item`)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Number of items owned"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "tierAlreadyUnlocked",
-      "msg": "Tier already unlocked"
+      "name": "playerName",
+      "docs": [
+        "Name registry entry - PDA seeded by the lowercase name",
+        "Makes sure no two players share a display name"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "Player authority that owns this name"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "docs": [
+              "The name as the player typed it"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "noPluginInstalled",
-      "msg": "No plugin installed in this slot"
+      "name": "playerPluginSlot",
+      "docs": [
+        "Player's plugin slot - each player has 10 slots (one per tier)",
+        "Represents an instance of a plugin installed by a player"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "player",
+            "docs": [
+              "The player who owns this slot"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tier",
+            "docs": [
+              "Which tier slot this is (1-10)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pluginId",
+            "docs": [
+              "Which plugin is installed (0 = empty slot)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "installedAt",
+            "docs": [
+              "When this plugin was installed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastClaim",
+            "docs": [
+              "Last time cookies were claimed from this plugin"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalClaimed",
+            "docs": [
+              "Total cookies claimed from this plugin instance (lifetime)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "nothingToClaim",
-      "msg": "Nothing to claim yet"
+      "name": "plugin",
+      "docs": [
+        "Plugin account - represents a player-created plugin template",
+        "One plugin can be installed by many players"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Account layout version"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pluginId",
+            "docs": [
+              "Unique sequential ID for this plugin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Creator of the plugin (receives revenue share)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tier",
+            "docs": [
+              "Tier of this plugin (1-10)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "metadataUri",
+            "docs": [
+              "URI to JSON metadata (icon, background, name, description)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "totalInstalls",
+            "docs": [
+              "Total number of times this plugin has been installed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "creatorEarnings",
+            "docs": [
+              "Total cookies earned by creator from this plugin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "When this plugin was created"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "creatorShareBps",
+            "docs": [
+              "Revenue share to creator in basis points (default 2000 = 20%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "burnShareBps",
+            "docs": [
+              "Revenue share burned in basis points (default 8000 = 80%)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pendingCreatorShareBps",
+            "docs": [
+              "Creator share that replaces `creator_share_bps` at `share_change_at`"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "shareChangeAt",
+            "docs": [
+              "When the pending share takes effect (0 = no change scheduled)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Listing and moderation status"
+            ],
+            "type": {
+              "defined": {
+                "name": "pluginStatus"
+              }
+            }
+          },
+          {
+            "name": "ratingSum",
+            "docs": [
+              "Sum of all player ratings (1-5 each)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ratingCount",
+            "docs": [
+              "Number of players who rated this plugin"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "Mint of the plugin's 1-of-1 NFT; its holder receives the install revenue"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "activeInstalls",
+            "docs": [
+              "Number of player slots that currently have this plugin installed"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "overflow",
-      "msg": "Arithmetic overflow"
-    }
-  ],
-  "types": [
-    {
-      "name": "gameData",
+      "name": "pluginRated",
+      "docs": [
+        "Emitted when a player rates a plugin or changes their rating"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "totalWoodCollected",
+            "name": "pluginId",
+            "type": "u64"
+          },
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "rating",
+            "docs": [
+              "Rating given, 1-5"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "ratingSum",
+            "docs": [
+              "Plugin totals after this rating"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "ratingCount",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "playerData",
+      "name": "pluginRating",
+      "docs": [
+        "A player's rating of a plugin - PDA seeded by (\"plugin_rating\", plugin, player)",
+        "Created unrated when the player first installs the plugin, which is what",
+        "proves they used it."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
+            "name": "plugin",
+            "docs": [
+              "Plugin being rated"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "name",
-            "type": "string"
+            "name": "player",
+            "docs": [
+              "Player who rated it"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "level",
+            "name": "rating",
+            "docs": [
+              "Rating from 1 to 5 (0 = not rated yet)"
+            ],
             "type": "u8"
           },
           {
-            "name": "xp",
+            "name": "ratedAt",
+            "docs": [
+              "When the rating was last set"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pluginRegistry",
+      "docs": [
+        "Program-wide plugin registry - singleton PDA seeded by \"plugin_registry\"",
+        "Hands out plugin IDs so every `Plugin` PDA is unique across all creators"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nextPluginId",
+            "docs": [
+              "ID the next created plugin gets (0 means \"no plugin\", so IDs start at 1)"
+            ],
             "type": "u64"
           },
           {
-            "name": "wood",
+            "name": "totalPlugins",
+            "docs": [
+              "Number of plugins created, including those migrated at init"
+            ],
             "type": "u64"
           },
           {
-            "name": "energy",
+            "name": "bump",
+            "docs": [
+              "Bump seed for PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pluginStatus",
+      "docs": [
+        "Whether a plugin can be installed and whether it still produces"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "active"
+          },
+          {
+            "name": "delisted"
+          },
+          {
+            "name": "hidden"
+          },
+          {
+            "name": "banned"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pluginStatusChanged",
+      "docs": [
+        "Emitted when a plugin is listed, delisted or moderated"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pluginId",
             "type": "u64"
           },
           {
-            "name": "lastLogin",
-            "type": "i64"
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "pluginStatus"
+              }
+            }
+          },
+          {
+            "name": "changedBy",
+            "docs": [
+              "Creator or admin who made the change"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pluginUpdated",
+      "docs": [
+        "Emitted when a creator edits a plugin"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pluginId",
+            "type": "u64"
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "creatorShareBps",
+            "docs": [
+              "Creator share in effect now, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "pendingCreatorShareBps",
+            "docs": [
+              "Share that applies at `share_change_at`"
+            ],
+            "type": "u16"
           },
           {
-            "name": "lastId",
-            "type": "u16"
+            "name": "shareChangeAt",
+            "docs": [
+              "When the pending share takes effect (0 = no change scheduled)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programAdmin",
+      "docs": [
+        "Program-level admin role - singleton PDA seeded by \"admin\"",
+        "Gates every privileged instruction (mint setup, config changes, ...)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Current admin key"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "unlockedTiers",
+            "name": "pendingAuthority",
             "docs": [
-              "Bitmask for unlocked tiers (bits 0-9 for tiers 1-10)",
-              "Tier 1 is unlocked by default (bit 0 = 1)"
+              "Admin proposed via `propose_admin`, waiting to call `accept_admin`"
             ],
-            "type": "u16"
+            "type": {
+              "option": "pubkey"
+            }
           },
           {
-            "name": "pluginGlobalCounter",
+            "name": "bump",
             "docs": [
-              "Global counter for creating unique plugin IDs"
+              "Bump seed for PDA"
             ],
-            "type": "u64"
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "playerPluginSlot",
+      "name": "recipe",
       "docs": [
-        "Player's plugin slot - each player has 10 slots (one per tier)",
-        "Represents an instance of a plugin installed by a player"
+        "Crafting recipe registered by the admin - PDA seeded by \"recipe\" and recipe_id"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "player",
+            "name": "recipeId",
             "docs": [
-              "The player who owns this slot"
+              "ID chosen by the admin"
             ],
-            "type": "pubkey"
+            "type": "u64"
           },
           {
-            "name": "tier",
+            "name": "woodCost",
             "docs": [
-              "Which tier slot this is (1-10)"
+              "Wood tokens burned per craft"
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "pluginId",
+            "name": "cookieCost",
             "docs": [
-              "Which plugin is installed (0 = empty slot)"
+              "Cookie tokens burned per craft"
             ],
             "type": "u64"
           },
           {
-            "name": "installedAt",
+            "name": "output",
             "docs": [
-              "When this plugin was installed"
+              "What the player gets"
             ],
-            "type": "i64"
+            "type": {
+              "defined": {
+                "name": "recipeOutput"
+              }
+            }
           },
           {
-            "name": "lastClaim",
+            "name": "enabled",
             "docs": [
-              "Last time cookies were claimed from this plugin"
+              "Disabled recipes can't be crafted"
             ],
-            "type": "i64"
+            "type": "bool"
           },
           {
-            "name": "totalClaimed",
+            "name": "timesCrafted",
             "docs": [
-              "Total cookies claimed from this plugin instance (lifetime)"
+              "Number of times this recipe was crafted"
             ],
             "type": "u64"
           },
@@ -1822,107 +6976,344 @@ export type Cookie = {
       }
     },
     {
-      "name": "plugin",
+      "name": "recipeOutput",
       "docs": [
-        "Plugin account - represents a player-created plugin template",
-        "One plugin can be installed by many players"
+        "What crafting a recipe gives the player"
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "pluginId",
-            "docs": [
-              "Unique sequential ID for this plugin"
-            ],
-            "type": "u64"
+            "name": "item",
+            "fields": [
+              {
+                "name": "itemId",
+                "type": "u32"
+              }
+            ]
           },
           {
-            "name": "creator",
-            "docs": [
-              "Creator of the plugin (receives revenue share)"
-            ],
-            "type": "pubkey"
+            "name": "energyRefill",
+            "fields": [
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
           },
           {
-            "name": "tier",
-            "docs": [
-              "Tier of this plugin (1-10)"
-            ],
-            "type": "u8"
-          },
+            "name": "productionBoost",
+            "fields": [
+              {
+                "name": "multiplierBps",
+                "type": "u32"
+              },
+              {
+                "name": "durationSeconds",
+                "type": "i64"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionToken",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "metadataUri",
-            "docs": [
-              "URI to JSON metadata (icon, background, name, description)"
-            ],
-            "type": "string"
+            "name": "authority",
+            "type": "pubkey"
           },
           {
-            "name": "totalInstalls",
-            "docs": [
-              "Total number of times this plugin has been installed"
-            ],
-            "type": "u64"
+            "name": "targetProgram",
+            "type": "pubkey"
           },
           {
-            "name": "creatorEarnings",
-            "docs": [
-              "Total cookies earned by creator from this plugin"
-            ],
-            "type": "u64"
+            "name": "sessionSigner",
+            "type": "pubkey"
           },
           {
-            "name": "createdAt",
-            "docs": [
-              "When this plugin was created"
-            ],
+            "name": "validUntil",
             "type": "i64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "treeFelled",
+      "docs": [
+        "Emitted when a chop fells the current tree of a level"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "creatorShareBps",
+            "name": "treeIndex",
             "docs": [
-              "Revenue share to creator in basis points (default 2000 = 20%)"
+              "Number of the tree that fell"
             ],
-            "type": "u16"
+            "type": "u64"
           },
           {
-            "name": "burnShareBps",
+            "name": "feller",
             "docs": [
-              "Revenue share burned in basis points (default 8000 = 80%)"
+              "Player who delivered the final chop"
             ],
-            "type": "u16"
+            "type": "pubkey"
           },
           {
-            "name": "bump",
+            "name": "bonusWood",
             "docs": [
-              "Bump seed for PDA"
+              "Bonus wood paid to the feller"
             ],
-            "type": "u8"
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "sessionToken",
+      "name": "updateConfigParams",
+      "docs": [
+        "New values for the game config. Fields left as `None` keep their current value."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
-            "type": "pubkey"
+            "name": "baseProductionRate",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "targetProgram",
-            "type": "pubkey"
+            "name": "creationBaseCost",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "sessionSigner",
-            "type": "pubkey"
+            "name": "installBaseCost",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "validUntil",
-            "type": "i64"
+            "name": "unlockBaseCost",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "creatorShareBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "burnShareBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "maxEnergy",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "timeToRefillEnergy",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "clickEnergyCost",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "xpPerClick",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "xpPerChop",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "xpPerClaim",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "levelXpBase",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxLevel",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "levelsPerTier",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "maxEnergyPerLevel",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "clickPowerPerTier",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "chopEnergyCost",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "woodPerAxeLevel",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "axeUpgradeBaseCost",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxAxeLevel",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "comboWindowSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "comboStepClicks",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "comboStepBonusBps",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "maxComboMultiplierBps",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "clickRateWindowSlots",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxClicksPerWindow",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "goldenCookieIntervalSlots",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "goldenCookieLifetimeSlots",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "goldenCookieLumpSum",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "goldenCookieFrenzyChance",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "frenzyDurationSeconds",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "frenzyMultiplierBps",
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "dailyRewardBase",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "dailyRewardPerStreakDay",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxDailyStreakBonusDays",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "treeHp",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "fellingBonusWood",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "shareChangeNoticeSeconds",
+            "type": {
+              "option": "i64"
+            }
           }
         ]
       }
@@ -62,6 +62,28 @@ export const [gameDataPDA] = PublicKey.findProgramAddressSync(
   PROGRAM_ID
 );
 
+export const [gameConfigPDA] = PublicKey.findProgramAddressSync(
+  [Buffer.from("config", "utf8")],
+  PROGRAM_ID
+);
+
+export const [woodMintPDA] = PublicKey.findProgramAddressSync(
+  [Buffer.from("wood", "utf8")],
+  PROGRAM_ID
+);
+
+// on_click and chop_tree take a nonce ahead of the player's lastId. Starts at
+// lastId + 1 and keeps counting from nonces still in flight, so quick clicks
+// don't reuse one before the player account update arrives.
+let lastNonce: number | null = null;
+export function nextNonce(lastId: number): number {
+  const ahead =
+    lastNonce === null ? 0 : (lastNonce - lastId + 0x10000) % 0x10000;
+  const base = ahead > 0 && ahead < 0x8000 ? lastNonce! : lastId;
+  lastNonce = (base + 1) % 0x10000;
+  return lastNonce;
+}
+
 // Player Data Account Type from Idl
 export type PlayerData = IdlAccounts<Cookie>["playerData"];
 export type GameData = IdlAccounts<Cookie>["gameData"];
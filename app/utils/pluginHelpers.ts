@@ -1,4 +1,29 @@
 // Plugin system helper functions
+import { PublicKey } from "@solana/web3.js";
+import { PROGRAM_ID } from "./anchor";
+
+/**
+ * Global plugin registry PDA, it hands out the plugin IDs
+ */
+export function getPluginRegistryPDA(): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("plugin_registry")],
+    PROGRAM_ID
+  )[0];
+}
+
+/**
+ * Plugin PDA for a plugin ID (IDs are unique across all creators)
+ */
+export function getPluginPDA(pluginId: number): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("plugin"),
+      new Uint8Array(new BigInt64Array([BigInt(pluginId)]).buffer),
+    ],
+    PROGRAM_ID
+  )[0];
+}
 
 /**
  * Calculate max accumulation hours for a tier (1 hour → 24 hours)
@@ -32,6 +57,16 @@ export function calculateCreationCost(tier: number): number {
   return 1000 * Math.pow(tier, 2);
 }
 
+/**
+ * Average player rating (1-5), or 0 if nobody rated the plugin yet
+ */
+export function getAverageRating(
+  ratingSum: number,
+  ratingCount: number
+): number {
+  return ratingCount > 0 ? ratingSum / ratingCount : 0;
+}
+
 /**
  * Calculate install cost for a plugin
  * Formula: 10 * (tier^1.5)
@@ -0,0 +1,81 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  GAME_DATA_SEED,
+  findPda,
+  initPlayer,
+  setupCookieMint,
+  setupGame,
+  setupTokenAccount,
+  setupWoodMint,
+  tokenBalance,
+} from "./helpers";
+
+// Must match the axe defaults in constants.rs
+const AXE_UPGRADE_BASE_COST = 100;
+
+describe("Axe upgrades", () => {
+  test("A better axe yields more wood for the same energy", async () => {
+    const { context, program, payer, gameConfigPDA } = await setupGame();
+    const cookieMintPDA = setupCookieMint(context, program);
+    const woodMintPDA = setupWoodMint(context, program);
+    const playerPDA = await initPlayer(program, payer);
+    const playerCookieAccount = setupTokenAccount(
+      context,
+      cookieMintPDA,
+      payer.publicKey,
+      AXE_UPGRADE_BASE_COST
+    );
+    const playerWoodAccount = getAssociatedTokenAddressSync(
+      woodMintPDA,
+      payer.publicKey
+    );
+
+    const upgradeAxe = () =>
+      program.methods
+        .upgradeAxe()
+        .accountsStrict({
+          player: payer.publicKey,
+          playerData: playerPDA,
+          gameConfig: gameConfigPDA,
+          playerTokenAccount: playerCookieAccount,
+          cookieMint: cookieMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    const chop = (counter: number, chops: number) =>
+      program.methods
+        .chopTree(GAME_DATA_SEED, counter, chops)
+        .accountsStrict({
+          player: playerPDA,
+          sessionToken: null,
+          signer: payer.publicKey,
+          gameData: findPda(program, Buffer.from(GAME_DATA_SEED)),
+          gameConfig: gameConfigPDA,
+          playerAuthority: payer.publicKey,
+          playerWoodAccount,
+          woodMint: woodMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+    await upgradeAxe();
+    expect(await tokenBalance(context, playerCookieAccount)).toEqual(0);
+
+    // Level 1 axe: 2 wood per chop, still 1 energy per chop
+    const before = await program.account.playerData.fetch(playerPDA);
+    await chop(1, 3);
+    const after = await program.account.playerData.fetch(playerPDA);
+    expect(after.axeLevel).toEqual(1);
+    expect(await tokenBalance(context, playerWoodAccount)).toEqual(6);
+    expect(before.energy.toNumber() - after.energy.toNumber()).toEqual(3);
+
+    // Can't chop more than the energy allows
+    await expect(chop(2, after.energy.toNumber() + 1)).rejects.toThrow(
+      /NotEnoughEnergy/
+    );
+  });
+});
@@ -0,0 +1,95 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { ClickProxy } from "../target/types/click_proxy";
+import {
+  initPlayer,
+  setupCookieMint,
+  setupGame,
+  updateConfig,
+} from "./helpers";
+
+// Must match the click rate defaults in constants.rs
+const MAX_CLICKS_PER_WINDOW = 200;
+const CLICK_RATE_WINDOW_SLOTS = 25;
+
+async function setupClicks() {
+  const game = await setupGame();
+  const { context, program, payer, gameConfigPDA } = game;
+  const cookieMintPDA = setupCookieMint(context, program);
+  const playerPDA = await initPlayer(program, payer);
+
+  // Accounts of on_click and on_click_batch, apart from the session token
+  const accounts = {
+    player: playerPDA,
+    gameConfig: gameConfigPDA,
+    signer: payer.publicKey,
+    playerAuthority: payer.publicKey,
+    playerTokenAccount: getAssociatedTokenAddressSync(
+      cookieMintPDA,
+      payer.publicKey
+    ),
+    rewardTokenMint: cookieMintPDA,
+    instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+    tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+    associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+    systemProgram: anchor.web3.SystemProgram.programId,
+  };
+  return { ...game, playerPDA, accounts };
+}
+
+describe("Click rate limit", () => {
+  test("Batches larger than the window limit are rejected", async () => {
+    const { program, playerPDA, accounts } = await setupClicks();
+
+    const clickBatch = (count: number, counter: number) =>
+      program.methods
+        .onClickBatch(count, counter)
+        .accountsStrict({ ...accounts, sessionToken: null })
+        .rpc();
+
+    // A batch can't be larger than the window limit
+    await expect(clickBatch(MAX_CLICKS_PER_WINDOW + 1, 1)).rejects.toThrow(
+      /InvalidClickCount/
+    );
+    await expect(clickBatch(0, 1)).rejects.toThrow(/InvalidClickCount/);
+
+    await clickBatch(5, 1);
+    const player = await program.account.playerData.fetch(playerPDA);
+    expect(player.clicksInWindow).toEqual(5);
+  });
+
+  test("Clicks beyond the window limit are rejected", async () => {
+    const { context, program, payer, accounts } = await setupClicks();
+    await updateConfig(program, payer, { maxClicksPerWindow: 3 });
+
+    const click = (counter: number) =>
+      program.methods
+        .onClick(counter)
+        .accountsStrict({ ...accounts, sessionToken: null })
+        .rpc();
+
+    // Each click is its own transaction
+    await click(1);
+    await click(2);
+    await click(3);
+    await expect(click(4)).rejects.toThrow(/ClickRateExceeded/);
+
+    // The next window starts over
+    const slot = await context.banksClient.getSlot();
+    context.warpToSlot(slot + BigInt(CLICK_RATE_WINDOW_SLOTS));
+    await click(5);
+  });
+
+  test("Clicks through a CPI are rejected", async () => {
+    const { program, accounts } = await setupClicks();
+    const proxy = anchor.workspace.ClickProxy as Program<ClickProxy>;
+
+    await expect(
+      proxy.methods
+        .click(1)
+        .accountsStrict({ ...accounts, cookieProgram: program.programId })
+        .rpc()
+    ).rejects.toThrow(/CpiNotAllowed/);
+  });
+});
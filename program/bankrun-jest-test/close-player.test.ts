@@ -0,0 +1,139 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { Clock } from "solana-bankrun";
+import {
+  createPlugin,
+  findPda,
+  fundedKeypair,
+  initPlayer,
+  initPluginRegistry,
+  installPlugin,
+  setupCookieMint,
+  setupGame,
+  setupTokenAccount,
+  tokenBalance,
+} from "./helpers";
+
+describe("Close player", () => {
+  test("Settles plugins and ratings once and keeps installed plugins alive", async () => {
+    const { context, program, payer, gameConfigPDA } = await setupGame();
+    const cookieMintPDA = setupCookieMint(context, program);
+    await initPluginRegistry(program, payer);
+    const creator = fundedKeypair(context);
+    const player = fundedKeypair(context);
+    await initPlayer(program, creator);
+    await initPlayer(program, player);
+
+    const plugin = await createPlugin(context, program, creator);
+    const slot = await installPlugin(context, program, player, plugin);
+    const rating = findPda(
+      program,
+      Buffer.from("plugin_rating"),
+      plugin.toBuffer(),
+      player.publicKey.toBuffer()
+    );
+    await program.methods
+      .ratePlugin(4)
+      .accountsStrict({
+        player: player.publicKey,
+        gameConfig: gameConfigPDA,
+        plugin,
+        pluginRating: rating,
+        nftHolderAccount: null,
+      })
+      .signers([player])
+      .rpc();
+
+    // Tier 1 produces 500 cookies an hour and holds at most one hour
+    const clock = await context.banksClient.getClock();
+    context.setClock(
+      new Clock(
+        clock.slot,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        clock.unixTimestamp + BigInt(7200)
+      )
+    );
+
+    const closePlayer = (
+      signer: anchor.web3.Keypair,
+      accounts: anchor.web3.PublicKey[]
+    ) =>
+      program.methods
+        .closePlayer()
+        .accountsStrict({
+          player: signer.publicKey,
+          playerData: findPda(
+            program,
+            Buffer.from("player"),
+            signer.publicKey.toBuffer()
+          ),
+          gameConfig: gameConfigPDA,
+          nameRegistry: null,
+          playerTokenAccount: setupTokenAccount(
+            context,
+            cookieMintPDA,
+            signer.publicKey,
+            0
+          ),
+          cookieMint: cookieMintPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          accounts.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .signers([signer])
+        .rpc();
+
+    // The creator's plugin is still installed by the player
+    await expect(closePlayer(creator, [plugin])).rejects.toThrow(
+      /PluginHasActiveInstalls/
+    );
+
+    // Passing the same slot twice would pay it out twice
+    await expect(
+      closePlayer(player, [slot, slot, rating, plugin])
+    ).rejects.toThrow(/InvalidAccount/);
+
+    // So does every occupied slot
+    await expect(closePlayer(player, [rating, plugin])).rejects.toThrow(
+      /MissingSlotAccount/
+    );
+
+    // The rating has to go too
+    await expect(closePlayer(player, [slot, plugin])).rejects.toThrow(
+      /MissingRatingAccount/
+    );
+
+    await closePlayer(player, [slot, rating, plugin]);
+    expect(
+      await tokenBalance(
+        context,
+        getAssociatedTokenAddressSync(cookieMintPDA, player.publicKey)
+      )
+    ).toEqual(500);
+    expect(await context.banksClient.getAccount(slot)).toBeNull();
+    expect(await context.banksClient.getAccount(rating)).toBeNull();
+    const { activeInstalls, ratingCount, ratingSum } =
+      await program.account.plugin.fetch(plugin);
+    expect(activeInstalls.toNumber()).toEqual(0);
+    expect(ratingCount.toNumber()).toEqual(0);
+    expect(ratingSum.toNumber()).toEqual(0);
+
+    // Once uninstalled the creator can leave too
+    await closePlayer(creator, [plugin]);
+    expect(
+      await context.banksClient.getAccount(
+        findPda(program, Buffer.from("player"), creator.publicKey.toBuffer())
+      )
+    ).toBeNull();
+  });
+});
@@ -0,0 +1,108 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  findPda,
+  initPlayer,
+  setupCookieMint,
+  setupGame,
+  setupTokenAccount,
+  setupWoodMint,
+  tokenBalance,
+} from "./helpers";
+
+describe("Crafting", () => {
+  test("Crafting burns the inputs and applies the output", async () => {
+    const { context, program, payer, programAdminPDA, gameConfigPDA } =
+      await setupGame();
+    const cookieMintPDA = setupCookieMint(context, program);
+    const woodMintPDA = setupWoodMint(context, program);
+    const playerPDA = await initPlayer(program, payer);
+    const playerWoodAccount = setupTokenAccount(
+      context,
+      woodMintPDA,
+      payer.publicKey,
+      30
+    );
+    const playerCookieAccount = setupTokenAccount(
+      context,
+      cookieMintPDA,
+      payer.publicKey,
+      100
+    );
+
+    const recipePDA = (recipeId: number) =>
+      findPda(
+        program,
+        Buffer.from("recipe"),
+        new anchor.BN(recipeId).toArrayLike(Buffer, "le", 8)
+      );
+    const itemPDA = (itemId: number) =>
+      findPda(
+        program,
+        Buffer.from("item"),
+        payer.publicKey.toBuffer(),
+        new anchor.BN(itemId).toArrayLike(Buffer, "le", 4)
+      );
+
+    const createRecipe = (
+      recipeId: number,
+      woodCost: number,
+      cookieCost: number,
+      output: any
+    ) =>
+      program.methods
+        .createRecipe(
+          new anchor.BN(recipeId),
+          new anchor.BN(woodCost),
+          new anchor.BN(cookieCost),
+          output
+        )
+        .accountsStrict({
+          admin: payer.publicKey,
+          programAdmin: programAdminPDA,
+          recipe: recipePDA(recipeId),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+    const craft = (recipeId: number, playerItem: anchor.web3.PublicKey) =>
+      program.methods
+        .craft(new anchor.BN(recipeId))
+        .accountsStrict({
+          player: payer.publicKey,
+          playerData: playerPDA,
+          gameConfig: gameConfigPDA,
+          recipe: recipePDA(recipeId),
+          playerItem,
+          playerWoodAccount,
+          woodMint: woodMintPDA,
+          playerCookieAccount,
+          cookieMint: cookieMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+    await createRecipe(1, 10, 25, { item: { itemId: 7 } });
+    await createRecipe(2, 5, 0, {
+      productionBoost: {
+        multiplierBps: 20000,
+        durationSeconds: new anchor.BN(60),
+      },
+    });
+
+    await craft(1, itemPDA(7));
+    await craft(1, itemPDA(7));
+    expect(await tokenBalance(context, playerWoodAccount)).toEqual(10);
+    expect(await tokenBalance(context, playerCookieAccount)).toEqual(50);
+    const item = await program.account.playerItem.fetch(itemPDA(7));
+    expect(item.amount.toNumber()).toEqual(2);
+
+    await craft(2, null);
+    expect(await tokenBalance(context, playerWoodAccount)).toEqual(5);
+    const player = await program.account.playerData.fetch(playerPDA);
+    expect(player.frenzyMultiplierBps).toEqual(20000);
+
+    // Item recipes need the item account
+    await expect(craft(1, null)).rejects.toThrow(/MissingItemAccount/);
+  });
+});
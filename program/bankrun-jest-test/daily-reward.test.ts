@@ -0,0 +1,81 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { Clock } from "solana-bankrun";
+import {
+  initPlayer,
+  setupCookieMint,
+  setupGame,
+  tokenBalance,
+} from "./helpers";
+
+// Must match the daily reward defaults in constants.rs
+const DAY = 86400;
+const DAILY_REWARD_BASE = 100;
+const DAILY_REWARD_PER_STREAK_DAY = 50;
+
+describe("Daily reward", () => {
+  test("Streak grows on consecutive days and resets after a gap", async () => {
+    const { context, program, payer, gameConfigPDA } = await setupGame();
+    const cookieMintPDA = setupCookieMint(context, program);
+    const playerPDA = await initPlayer(program, payer);
+    const playerTokenAccount = getAssociatedTokenAddressSync(
+      cookieMintPDA,
+      payer.publicKey
+    );
+
+    const setTime = async (unixTimestamp: number) => {
+      const clock = await context.banksClient.getClock();
+      context.setClock(
+        new Clock(
+          clock.slot,
+          clock.epochStartTimestamp,
+          clock.epoch,
+          clock.leaderScheduleEpoch,
+          BigInt(unixTimestamp)
+        )
+      );
+    };
+
+    const claim = () =>
+      program.methods
+        .claimDailyReward()
+        .accountsStrict({
+          player: payer.publicKey,
+          playerData: playerPDA,
+          gameConfig: gameConfigPDA,
+          playerTokenAccount,
+          cookieMint: cookieMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+    const balance = () => tokenBalance(context, playerTokenAccount);
+
+    const day0 = 20_000 * DAY;
+
+    await setTime(day0 + 3600);
+    await claim();
+    expect(await balance()).toEqual(DAILY_REWARD_BASE);
+
+    // Same UTC day
+    await setTime(day0 + DAY - 1);
+    await expect(claim()).rejects.toThrow(/DailyRewardAlreadyClaimed/);
+
+    // Next day extends the streak
+    await setTime(day0 + DAY);
+    await claim();
+    expect(await balance()).toEqual(
+      2 * DAILY_REWARD_BASE + DAILY_REWARD_PER_STREAK_DAY
+    );
+    let player = await program.account.playerData.fetch(playerPDA);
+    expect(player.dailyStreak).toEqual(2);
+
+    // Skipping a day starts over
+    await setTime(day0 + 3 * DAY);
+    await claim();
+    player = await program.account.playerData.fetch(playerPDA);
+    expect(player.dailyStreak).toEqual(1);
+  });
+});
@@ -1,19 +1,12 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
-import { Cookie } from "../target/types/cookie";
-import { Clock, startAnchor } from "solana-bankrun";
-import { BankrunProvider } from "anchor-bankrun";
+import { Clock } from "solana-bankrun";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { setupGame, setupWoodMint } from "./helpers";
 
 describe("Energy refill", () => {
   test("Turning forward time refills energy", async () => {
-    const context = await startAnchor(".", [], []);
+    const { context, program, payer, gameConfigPDA } = await setupGame();
     const client = context.banksClient;
-
-    const provider = new BankrunProvider(context);
-    anchor.setProvider(provider);
-
-    const program = anchor.workspace.Cookie as Program<Cookie>;
-    const payer = provider.wallet as anchor.Wallet;
     const gameDataSeed = "gameData";
 
     const [playerPDA] = anchor.web3.PublicKey.findProgramAddressSync(
@@ -26,6 +19,8 @@ describe("Energy refill", () => {
       program.programId
     );
 
+    const woodMintPDA = setupWoodMint(context, program);
+
     try {
       let tx = await program.methods
         .initPlayer(gameDataSeed)
@@ -33,6 +28,7 @@ describe("Energy refill", () => {
           player: playerPDA,
           signer: payer.publicKey,
           gameData: gameDataPDA,
+          gameConfig: gameConfigPDA,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc({ skipPreflight: true });
@@ -48,12 +44,21 @@ describe("Energy refill", () => {
       console.log(`Chop instruction ${i}`);
 
       let tx = await program.methods
-        .chopTree(gameDataSeed, i)
+        .chopTree(gameDataSeed, i + 1, 1)
         .accountsStrict({
           player: playerPDA,
           sessionToken: null,
           signer: payer.publicKey,
           gameData: gameDataPDA,
+          gameConfig: gameConfigPDA,
+          playerAuthority: payer.publicKey,
+          playerWoodAccount: getAssociatedTokenAddressSync(
+            woodMintPDA,
+            payer.publicKey
+          ),
+          woodMint: woodMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc();
@@ -88,12 +93,21 @@ describe("Energy refill", () => {
     // Chop another tree, so that the energy is updated in the account.
     // (Usually the client predicts the time and updates the energy)
     let tx = await program.methods
-      .chopTree(gameDataSeed, 0)
+      .chopTree(gameDataSeed, 12, 1)
       .accountsStrict({
         player: playerPDA,
         sessionToken: null,
         signer: payer.publicKey,
         gameData: gameDataPDA,
+        gameConfig: gameConfigPDA,
+        playerAuthority: payer.publicKey,
+        playerWoodAccount: getAssociatedTokenAddressSync(
+          woodMintPDA,
+          payer.publicKey
+        ),
+        woodMint: woodMintPDA,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .rpc();
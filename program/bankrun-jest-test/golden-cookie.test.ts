@@ -0,0 +1,87 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  findPda,
+  fundedKeypair,
+  initPlayer,
+  setupCookieMint,
+  setupGame,
+  tokenBalance,
+} from "./helpers";
+
+// Must match the golden cookie defaults in constants.rs
+const INTERVAL_SLOTS = 9000;
+const LIFETIME_SLOTS = 150;
+const LUMP_SUM = 500;
+
+describe("Golden cookie", () => {
+  test("Only the first claim of a live golden cookie pays out", async () => {
+    const { context, program, payer, gameConfigPDA } = await setupGame();
+    const cookieMintPDA = setupCookieMint(context, program);
+    const other = fundedKeypair(context);
+    const playerPDA = await initPlayer(program, payer);
+    const otherPDA = await initPlayer(program, other);
+    const goldenCookiePDA = findPda(program, Buffer.from("golden_cookie"));
+
+    const claim = (
+      player: anchor.web3.PublicKey,
+      signer: anchor.web3.Keypair | anchor.Wallet
+    ) => {
+      const builder = program.methods.claimGoldenCookie().accountsStrict({
+        sessionToken: null,
+        player,
+        gameConfig: gameConfigPDA,
+        goldenCookie: goldenCookiePDA,
+        signer: signer.publicKey,
+        playerAuthority: signer.publicKey,
+        playerTokenAccount: getAssociatedTokenAddressSync(
+          cookieMintPDA,
+          signer.publicKey
+        ),
+        rewardTokenMint: cookieMintPDA,
+        slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      });
+      return signer instanceof anchor.web3.Keypair
+        ? builder.signers([signer]).rpc()
+        : builder.rpc();
+    };
+
+    // Between spawns nothing can be claimed
+    context.warpToSlot(BigInt(2 * INTERVAL_SLOTS + LIFETIME_SLOTS + 10));
+    await expect(claim(playerPDA, payer)).rejects.toThrow(/NoGoldenCookie/);
+
+    // The next spawn goes to whoever is first
+    context.warpToSlot(BigInt(3 * INTERVAL_SLOTS + 10));
+    await claim(playerPDA, payer);
+    await expect(claim(otherPDA, other)).rejects.toThrow(
+      /GoldenCookieClaimed/
+    );
+
+    const goldenCookie = await program.account.goldenCookie.fetch(
+      goldenCookiePDA
+    );
+    expect(goldenCookie.lastClaimedRound.toNumber()).toEqual(3);
+    expect(goldenCookie.lastClaimedBy.toBase58()).toEqual(
+      payer.publicKey.toBase58()
+    );
+
+    // The reward is either a lump sum or a frenzy
+    const player = await program.account.playerData.fetch(playerPDA);
+    const balance = await tokenBalance(
+      context,
+      getAssociatedTokenAddressSync(cookieMintPDA, payer.publicKey)
+    );
+    if (player.frenzyEnd.toNumber() > 0) {
+      expect(player.frenzyMultiplierBps).toBeGreaterThan(10000);
+    } else {
+      expect(balance).toEqual(LUMP_SUM);
+    }
+
+    // And the spawn after that is up for grabs again
+    context.warpToSlot(BigInt(4 * INTERVAL_SLOTS));
+    await claim(otherPDA, other);
+  });
+});
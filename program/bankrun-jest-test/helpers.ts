@@ -0,0 +1,421 @@
+import * as anchor from "@coral-xyz/anchor";
+import { IdlTypes, Program } from "@coral-xyz/anchor";
+import { Cookie } from "../target/types/cookie";
+import { ProgramTestContext, startAnchor } from "solana-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import {
+  ACCOUNT_SIZE,
+  AccountLayout,
+  AccountState,
+  MINT_SIZE,
+  MintLayout,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+
+export const GAME_DATA_SEED = "gameData";
+
+export function findPda(program: Program<Cookie>, ...seeds: Buffer[]) {
+  return anchor.web3.PublicKey.findProgramAddressSync(
+    seeds,
+    program.programId
+  )[0];
+}
+
+// Starts bankrun with the program admin and game config in place.
+// Bankrun doesn't deploy the program as upgradeable, so init_admin can't be
+// called. The admin account is written directly instead.
+export async function setupGame() {
+  const context = await startAnchor(".", [], []);
+  const provider = new BankrunProvider(context);
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.Cookie as Program<Cookie>;
+  const payer = provider.wallet as anchor.Wallet;
+
+  const [programAdminPDA, adminBump] =
+    anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("admin")],
+      program.programId
+    );
+  const gameConfigPDA = findPda(program, Buffer.from("config"));
+
+  const adminData = await program.coder.accounts.encode("programAdmin", {
+    authority: payer.publicKey,
+    pendingAuthority: null,
+    bump: adminBump,
+  });
+  context.setAccount(programAdminPDA, {
+    lamports: 1_000_000_000,
+    data: adminData,
+    owner: program.programId,
+    executable: false,
+  });
+
+  await program.methods
+    .initConfig()
+    .accountsStrict({
+      admin: payer.publicKey,
+      programAdmin: programAdminPDA,
+      gameConfig: gameConfigPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc();
+
+  return { context, provider, program, payer, programAdminPDA, gameConfigPDA };
+}
+
+// Creates and funds a new wallet
+export function fundedKeypair(context: ProgramTestContext) {
+  const keypair = anchor.web3.Keypair.generate();
+  context.setAccount(keypair.publicKey, {
+    lamports: 10_000_000_000,
+    data: Buffer.alloc(0),
+    owner: anchor.web3.SystemProgram.programId,
+    executable: false,
+  });
+  return keypair;
+}
+
+export async function initPlayer(
+  program: Program<Cookie>,
+  signer: anchor.web3.Keypair | anchor.Wallet
+) {
+  const playerPDA = findPda(
+    program,
+    Buffer.from("player"),
+    signer.publicKey.toBuffer()
+  );
+
+  const builder = program.methods.initPlayer(GAME_DATA_SEED).accountsStrict({
+    player: playerPDA,
+    signer: signer.publicKey,
+    gameData: findPda(program, Buffer.from(GAME_DATA_SEED)),
+    gameConfig: findPda(program, Buffer.from("config")),
+    systemProgram: anchor.web3.SystemProgram.programId,
+  });
+  if (signer instanceof anchor.web3.Keypair) {
+    await builder.signers([signer]).rpc();
+  } else {
+    await builder.rpc();
+  }
+
+  return playerPDA;
+}
+
+// Writes a program mint directly. create_mint needs the Metaplex program,
+// which isn't loaded in bankrun.
+function setupMint(
+  context: ProgramTestContext,
+  program: Program<Cookie>,
+  seed: string
+) {
+  const mintPDA = findPda(program, Buffer.from(seed));
+  const data = Buffer.alloc(MINT_SIZE);
+  MintLayout.encode(
+    {
+      mintAuthorityOption: 1,
+      mintAuthority: mintPDA,
+      supply: BigInt(0),
+      decimals: 0,
+      isInitialized: true,
+      freezeAuthorityOption: 0,
+      freezeAuthority: anchor.web3.PublicKey.default,
+    },
+    data
+  );
+  context.setAccount(mintPDA, {
+    lamports: 1_000_000_000,
+    data,
+    owner: TOKEN_PROGRAM_ID,
+    executable: false,
+  });
+  return mintPDA;
+}
+
+export function setupCookieMint(
+  context: ProgramTestContext,
+  program: Program<Cookie>
+) {
+  return setupMint(context, program, "reward");
+}
+
+export function setupWoodMint(
+  context: ProgramTestContext,
+  program: Program<Cookie>
+) {
+  return setupMint(context, program, "wood");
+}
+
+// Reads the raw amount of an SPL token account
+export async function tokenBalance(
+  context: ProgramTestContext,
+  tokenAccount: anchor.web3.PublicKey
+) {
+  const account = await context.banksClient.getAccount(tokenAccount);
+  return account ? Number(Buffer.from(account.data).readBigUInt64LE(64)) : 0;
+}
+
+// Writes an associated token account holding `amount` tokens directly
+export function setupTokenAccount(
+  context: ProgramTestContext,
+  mint: anchor.web3.PublicKey,
+  owner: anchor.web3.PublicKey,
+  amount: number
+) {
+  const address = getAssociatedTokenAddressSync(mint, owner);
+  const data = Buffer.alloc(ACCOUNT_SIZE);
+  AccountLayout.encode(
+    {
+      mint,
+      owner,
+      amount: BigInt(amount),
+      delegateOption: 0,
+      delegate: anchor.web3.PublicKey.default,
+      state: AccountState.Initialized,
+      isNativeOption: 0,
+      isNative: BigInt(0),
+      delegatedAmount: BigInt(0),
+      closeAuthorityOption: 0,
+      closeAuthority: anchor.web3.PublicKey.default,
+    },
+    data
+  );
+  context.setAccount(address, {
+    lamports: 1_000_000_000,
+    data,
+    owner: TOKEN_PROGRAM_ID,
+    executable: false,
+  });
+  return address;
+}
+
+export function pluginPda(program: Program<Cookie>, pluginId: number) {
+  return findPda(
+    program,
+    Buffer.from("plugin"),
+    new anchor.BN(pluginId).toArrayLike(Buffer, "le", 8)
+  );
+}
+
+// Writes a plugin with the layout from before the version header, as
+// create_plugin used to make them
+export function setLegacyPlugin(
+  context: ProgramTestContext,
+  program: Program<Cookie>,
+  pluginId: number,
+  creator: anchor.web3.PublicKey,
+  tier = 1
+) {
+  const [plugin, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("plugin"),
+      new anchor.BN(pluginId).toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  );
+  const uri = "https://example.com/plugin.json";
+  const data = Buffer.alloc(8 + 8 + 32 + 1 + 4 + 200 + 8 + 8 + 8 + 2 + 2 + 1);
+  let offset = Buffer.from(
+    program.idl.accounts.find((a) => a.name === "plugin").discriminator
+  ).copy(data, 0);
+  offset = data.writeBigUInt64LE(BigInt(pluginId), offset);
+  offset += creator.toBuffer().copy(data, offset);
+  offset = data.writeUInt8(tier, offset);
+  offset = data.writeUInt32LE(uri.length, offset);
+  offset += data.write(uri, offset);
+  offset = data.writeBigUInt64LE(BigInt(0), offset); // total_installs
+  offset = data.writeBigUInt64LE(BigInt(0), offset); // creator_earnings
+  offset = data.writeBigInt64LE(BigInt(0), offset); // created_at
+  offset = data.writeUInt16LE(2000, offset); // creator_share_bps
+  offset = data.writeUInt16LE(8000, offset); // burn_share_bps
+  data.writeUInt8(bump, offset);
+
+  context.setAccount(plugin, {
+    lamports: 1_000_000_000,
+    data,
+    owner: program.programId,
+    executable: false,
+  });
+  return plugin;
+}
+
+// Writes a plugin slot with the layout from before the version header
+export function setLegacySlot(
+  context: ProgramTestContext,
+  program: Program<Cookie>,
+  player: anchor.web3.PublicKey,
+  tier: number,
+  pluginId: number
+) {
+  const [slot, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("player_plugin_slot"), player.toBuffer(), Buffer.from([tier])],
+    program.programId
+  );
+  const data = Buffer.alloc(8 + 32 + 1 + 8 + 8 + 8 + 8 + 1);
+  let offset = Buffer.from(
+    program.idl.accounts.find((a) => a.name === "playerPluginSlot")
+      .discriminator
+  ).copy(data, 0);
+  offset += player.toBuffer().copy(data, offset);
+  offset = data.writeUInt8(tier, offset);
+  offset = data.writeBigUInt64LE(BigInt(pluginId), offset);
+  offset = data.writeBigInt64LE(BigInt(0), offset); // installed_at
+  offset = data.writeBigInt64LE(BigInt(0), offset); // last_claim
+  offset = data.writeBigUInt64LE(BigInt(0), offset); // total_claimed
+  data.writeUInt8(bump, offset);
+
+  context.setAccount(slot, {
+    lamports: 1_000_000_000,
+    data,
+    owner: program.programId,
+    executable: false,
+  });
+  return slot;
+}
+
+// Changes the given config fields as the admin. Fields left out are encoded
+// as None, so they keep their current value.
+export async function updateConfig(
+  program: Program<Cookie>,
+  admin: anchor.Wallet,
+  params: Partial<IdlTypes<Cookie>["updateConfigParams"]>
+) {
+  await program.methods
+    .updateConfig(params as IdlTypes<Cookie>["updateConfigParams"])
+    .accountsStrict({
+      admin: admin.publicKey,
+      programAdmin: findPda(program, Buffer.from("admin")),
+      gameConfig: findPda(program, Buffer.from("config")),
+    })
+    .rpc();
+}
+
+export async function initPluginRegistry(
+  program: Program<Cookie>,
+  admin: anchor.Wallet,
+  nextPluginId = 1
+) {
+  const pluginRegistryPDA = findPda(program, Buffer.from("plugin_registry"));
+  await program.methods
+    .initPluginRegistry(new anchor.BN(nextPluginId))
+    .accountsStrict({
+      admin: admin.publicKey,
+      programAdmin: findPda(program, Buffer.from("admin")),
+      pluginRegistry: pluginRegistryPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc();
+  return pluginRegistryPDA;
+}
+
+// Creates a plugin through the registry, funding the creator's cookie account
+// with enough to pay for it. The creator needs an initialized player.
+export async function createPlugin(
+  context: ProgramTestContext,
+  program: Program<Cookie>,
+  creator: anchor.web3.Keypair,
+  tier = 1,
+  creatorShareBps = 2000
+) {
+  const cookieMintPDA = findPda(program, Buffer.from("reward"));
+  const pluginRegistryPDA = findPda(program, Buffer.from("plugin_registry"));
+  const registry = await program.account.pluginRegistry.fetch(
+    pluginRegistryPDA
+  );
+  const plugin = pluginPda(program, registry.nextPluginId.toNumber());
+
+  await program.methods
+    .createPlugin(tier, "https://example.com/plugin.json", creatorShareBps)
+    .accountsStrict({
+      creator: creator.publicKey,
+      playerData: findPda(
+        program,
+        Buffer.from("player"),
+        creator.publicKey.toBuffer()
+      ),
+      gameConfig: findPda(program, Buffer.from("config")),
+      pluginRegistry: pluginRegistryPDA,
+      plugin,
+      creatorTokenAccount: setupTokenAccount(
+        context,
+        cookieMintPDA,
+        creator.publicKey,
+        1_000_000
+      ),
+      cookieMint: cookieMintPDA,
+      // Minting the plugin NFT needs the Metaplex program, which isn't loaded
+      pluginNftMint: null,
+      creatorNftAccount: null,
+      nftMetadata: null,
+      nftMasterEdition: null,
+      tokenMetadataProgram: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: null,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      rent: null,
+    })
+    .signers([creator])
+    .rpc();
+  return plugin;
+}
+
+// Installs a plugin into the player's slot for its tier, funding the
+// player's cookie account with enough to pay for it. Revenue goes to the
+// creator unless a recipient and the NFT account backing it are given.
+export async function installPlugin(
+  context: ProgramTestContext,
+  program: Program<Cookie>,
+  player: anchor.web3.Keypair,
+  plugin: anchor.web3.PublicKey,
+  previousPlugin: anchor.web3.PublicKey | null = null,
+  revenueRecipient: anchor.web3.PublicKey | null = null,
+  nftHolderAccount: anchor.web3.PublicKey | null = null
+) {
+  const cookieMintPDA = findPda(program, Buffer.from("reward"));
+  const { tier, creator } = await program.account.plugin.fetch(plugin);
+  const playerPluginSlot = findPda(
+    program,
+    Buffer.from("player_plugin_slot"),
+    player.publicKey.toBuffer(),
+    Buffer.from([tier])
+  );
+
+  await program.methods
+    .installPlugin()
+    .accountsStrict({
+      player: player.publicKey,
+      playerData: findPda(
+        program,
+        Buffer.from("player"),
+        player.publicKey.toBuffer()
+      ),
+      gameConfig: findPda(program, Buffer.from("config")),
+      plugin,
+      previousPlugin,
+      playerPluginSlot,
+      pluginRating: findPda(
+        program,
+        Buffer.from("plugin_rating"),
+        plugin.toBuffer(),
+        player.publicKey.toBuffer()
+      ),
+      playerTokenAccount: setupTokenAccount(
+        context,
+        cookieMintPDA,
+        player.publicKey,
+        1_000_000
+      ),
+      creatorTokenAccount: getAssociatedTokenAddressSync(
+        cookieMintPDA,
+        revenueRecipient ?? creator
+      ),
+      nftHolderAccount,
+      cookieMint: cookieMintPDA,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .signers([player])
+    .rpc();
+  return playerPluginSlot;
+}
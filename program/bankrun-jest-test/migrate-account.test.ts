@@ -0,0 +1,297 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Cookie } from "../target/types/cookie";
+import { startAnchor } from "solana-bankrun";
+import { BankrunProvider } from "anchor-bankrun";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  GAME_DATA_SEED,
+  findPda,
+  fundedKeypair,
+  initPlayer,
+  setLegacyPlugin,
+  setLegacySlot,
+  setupGame,
+  setupWoodMint,
+  tokenBalance,
+} from "./helpers";
+
+describe("Account migration", () => {
+  test("Legacy PlayerData is upgraded and shrunk", async () => {
+    const context = await startAnchor(".", [], []);
+    const client = context.banksClient;
+
+    const provider = new BankrunProvider(context);
+    anchor.setProvider(provider);
+
+    const program = anchor.workspace.Cookie as Program<Cookie>;
+    const payer = provider.wallet as anchor.Wallet;
+
+    const [playerPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("player"), payer.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // Build a PlayerData account with the pre-version layout and the old
+    // fixed size of 1000 bytes
+    const discriminator = Buffer.from(
+      program.idl.accounts.find((a) => a.name === "playerData").discriminator
+    );
+
+    const legacy = Buffer.alloc(1000);
+    let offset = 0;
+    offset += discriminator.copy(legacy, offset);
+    offset += payer.publicKey.toBuffer().copy(legacy, offset);
+    offset = legacy.writeUInt32LE(0, offset); // name
+    offset = legacy.writeUInt8(3, offset); // level
+    offset = legacy.writeBigUInt64LE(BigInt(42), offset); // xp
+    offset = legacy.writeBigUInt64LE(BigInt(7), offset); // wood
+    offset = legacy.writeBigUInt64LE(BigInt(55), offset); // energy
+    offset = legacy.writeBigInt64LE(BigInt(1000), offset); // last_login
+    offset = legacy.writeUInt16LE(9, offset); // last_id
+    offset = legacy.writeUInt16LE(1, offset); // unlocked_tiers
+    legacy.writeBigUInt64LE(BigInt(1), offset); // plugin_global_counter
+
+    const legacyLamports = 1_000_000_000;
+    context.setAccount(playerPDA, {
+      lamports: legacyLamports,
+      data: legacy,
+      owner: program.programId,
+      executable: false,
+    });
+
+    await program.methods
+      .migrateAccount()
+      .accountsStrict({
+        authority: payer.publicKey,
+        account: playerPDA,
+        programAdmin: null,
+        plugin: null,
+        playerData: null,
+        gameConfig: null,
+        pluginRating: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const info = await client.getAccount(playerPDA);
+    expect(info.data.length).toBeLessThan(1000);
+    expect(Number(info.lamports)).toBeLessThan(legacyLamports);
+
+    const player = await program.account.playerData.fetch(playerPDA);
+    expect(player.version).toEqual(1);
+    expect(player.authority.toBase58()).toEqual(payer.publicKey.toBase58());
+    expect(player.level).toEqual(3);
+    expect(player.xp.toNumber()).toEqual(42);
+    expect(player.wood.toNumber()).toEqual(7);
+    expect(player.energy.toNumber()).toEqual(55);
+    expect(player.lastId).toEqual(9);
+
+    // Running it again is rejected
+    await expect(
+      program.methods
+        .migrateAccount()
+        .accountsStrict({
+          authority: payer.publicKey,
+          account: playerPDA,
+          programAdmin: null,
+          plugin: null,
+          playerData: null,
+          gameConfig: null,
+          pluginRating: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc()
+    ).rejects.toThrow(/AlreadyMigrated/);
+  });
+
+  test("Wood counter is converted into wood tokens", async () => {
+    const { context, program, payer } = await setupGame();
+    const woodMintPDA = setupWoodMint(context, program);
+    const playerPDA = await initPlayer(program, payer);
+    const playerWoodAccount = getAssociatedTokenAddressSync(
+      woodMintPDA,
+      payer.publicKey
+    );
+
+    // Give the player some wood from before wood was a token
+    const player = await program.account.playerData.fetch(playerPDA);
+    const info = await context.banksClient.getAccount(playerPDA);
+    const data = await program.coder.accounts.encode("playerData", {
+      ...player,
+      wood: new anchor.BN(7),
+    });
+    context.setAccount(playerPDA, {
+      ...info,
+      data: Buffer.concat([data, Buffer.alloc(info.data.length - data.length)]),
+    });
+
+    // Anyone can pay for the conversion
+    const payerOfConversion = fundedKeypair(context);
+    const migrateWood = () =>
+      program.methods
+        .migrateWood()
+        .accountsStrict({
+          payer: payerOfConversion.publicKey,
+          player: playerPDA,
+          playerAuthority: payer.publicKey,
+          playerWoodAccount,
+          woodMint: woodMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([payerOfConversion])
+        .rpc();
+
+    await migrateWood();
+    expect(await tokenBalance(context, playerWoodAccount)).toEqual(7);
+    const migrated = await program.account.playerData.fetch(playerPDA);
+    expect(migrated.wood.toNumber()).toEqual(0);
+
+    await expect(migrateWood()).rejects.toThrow(/NoWoodToMigrate/);
+  });
+
+  test("Unmigrated GameData is rejected instead of reinterpreted", async () => {
+    const { context, program, payer } = await setupGame();
+    const gameDataPDA = findPda(program, Buffer.from(GAME_DATA_SEED));
+
+    // GameData with the pre-version layout and the old fixed size
+    const discriminator = Buffer.from(
+      program.idl.accounts.find((a) => a.name === "gameData").discriminator
+    );
+    const legacy = Buffer.alloc(1000);
+    discriminator.copy(legacy, 0);
+    legacy.writeBigUInt64LE(BigInt(5000), 8); // total_wood_collected
+    context.setAccount(gameDataPDA, {
+      lamports: 1_000_000_000,
+      data: legacy,
+      owner: program.programId,
+      executable: false,
+    });
+
+    await expect(initPlayer(program, payer)).rejects.toThrow(
+      /AccountNotMigrated/
+    );
+  });
+
+  test("The admin can migrate a legacy plugin for its creator", async () => {
+    const { context, program, payer, programAdminPDA } = await setupGame();
+    const creator = fundedKeypair(context);
+    const plugin = setLegacyPlugin(context, program, 1, creator.publicKey);
+
+    // Only the creator or the admin
+    const stranger = fundedKeypair(context);
+    await expect(
+      program.methods
+        .migrateAccount()
+        .accountsStrict({
+          authority: stranger.publicKey,
+          account: plugin,
+          programAdmin: null,
+          plugin: null,
+          playerData: null,
+          gameConfig: null,
+          pluginRating: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([stranger])
+        .rpc()
+    ).rejects.toThrow(/WrongAuthority/);
+
+    await program.methods
+      .migrateAccount()
+      .accountsStrict({
+        authority: payer.publicKey,
+        account: plugin,
+        programAdmin: programAdminPDA,
+        plugin: null,
+        playerData: null,
+        gameConfig: null,
+        pluginRating: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const migrated = await program.account.plugin.fetch(plugin);
+    expect(migrated.version).toEqual(1);
+    expect(migrated.pluginId.toNumber()).toEqual(1);
+    expect(migrated.creator.toBase58()).toEqual(creator.publicKey.toBase58());
+    expect(migrated.creatorShareBps).toEqual(2000);
+  });
+
+  test("Migrated slots count on their plugin and player again", async () => {
+    const { context, program, payer, programAdminPDA, gameConfigPDA } =
+      await setupGame();
+    const creator = fundedKeypair(context);
+    const player = fundedKeypair(context);
+    const playerPDA = await initPlayer(program, player);
+    const plugin = setLegacyPlugin(context, program, 1, creator.publicKey);
+    const slot = setLegacySlot(context, program, player.publicKey, 1, 1);
+
+    const migrate = (
+      authority: anchor.web3.Keypair,
+      account: anchor.web3.PublicKey,
+      programAdmin: anchor.web3.PublicKey | null,
+      installedPlugin: anchor.web3.PublicKey | null
+    ) =>
+      program.methods
+        .migrateAccount()
+        .accountsStrict({
+          authority: authority.publicKey,
+          account,
+          programAdmin,
+          plugin: installedPlugin,
+          playerData: installedPlugin && playerPDA,
+          gameConfig: installedPlugin && gameConfigPDA,
+          pluginRating:
+            installedPlugin &&
+            findPda(
+              program,
+              Buffer.from("plugin_rating"),
+              installedPlugin.toBuffer(),
+              authority.publicKey.toBuffer()
+            ),
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+    await migrate(payer.payer, plugin, programAdminPDA, null);
+    await expect(migrate(player, slot, null, null)).rejects.toThrow(
+      /MissingPluginAccount/
+    );
+    await migrate(player, slot, null, plugin);
+
+    const migrated = await program.account.playerPluginSlot.fetch(slot);
+    expect(migrated.version).toEqual(1);
+    expect(migrated.pluginId.toNumber()).toEqual(1);
+    const { activeInstalls } = await program.account.plugin.fetch(plugin);
+    expect(activeInstalls.toNumber()).toEqual(1);
+    const { installedTiers, clickPower, usedPlugins } =
+      await program.account.playerData.fetch(playerPDA);
+    expect(installedTiers).toEqual(1);
+    expect(clickPower.toNumber()).toEqual(2);
+    expect(usedPlugins).toEqual(1);
+
+    // The player can rate the plugin they already had installed
+    await program.methods
+      .ratePlugin(4)
+      .accountsStrict({
+        player: player.publicKey,
+        gameConfig: gameConfigPDA,
+        plugin,
+        pluginRating: findPda(
+          program,
+          Buffer.from("plugin_rating"),
+          plugin.toBuffer(),
+          player.publicKey.toBuffer()
+        ),
+        nftHolderAccount: null,
+      })
+      .signers([player])
+      .rpc();
+    const { ratingCount } = await program.account.plugin.fetch(plugin);
+    expect(ratingCount.toNumber()).toEqual(1);
+  });
+});
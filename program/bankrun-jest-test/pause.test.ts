@@ -0,0 +1,77 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { setupGame, setupWoodMint, tokenBalance } from "./helpers";
+
+// Must match the PAUSE_* flags in constants.rs
+const PAUSE_CHOPPING = 1 << 1;
+
+describe("Pause switch", () => {
+  test("Paused chopping is rejected until resumed", async () => {
+    const { context, program, payer, programAdminPDA, gameConfigPDA } =
+      await setupGame();
+    const gameDataSeed = "gameData";
+
+    const [playerPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("player"), payer.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const [gameDataPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from(gameDataSeed)],
+      program.programId
+    );
+
+    await program.methods
+      .initPlayer(gameDataSeed)
+      .accountsStrict({
+        player: playerPDA,
+        signer: payer.publicKey,
+        gameData: gameDataPDA,
+        gameConfig: gameConfigPDA,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const woodMintPDA = setupWoodMint(context, program);
+    const playerWoodAccount = getAssociatedTokenAddressSync(
+      woodMintPDA,
+      payer.publicKey
+    );
+
+    const setPaused = (flags: number) =>
+      program.methods
+        .setPaused(flags)
+        .accountsStrict({
+          admin: payer.publicKey,
+          programAdmin: programAdminPDA,
+          gameConfig: gameConfigPDA,
+        })
+        .rpc();
+
+    const chop = (counter: number) =>
+      program.methods
+        .chopTree(gameDataSeed, counter, 1)
+        .accountsStrict({
+          player: playerPDA,
+          sessionToken: null,
+          signer: payer.publicKey,
+          gameData: gameDataPDA,
+          gameConfig: gameConfigPDA,
+          playerAuthority: payer.publicKey,
+          playerWoodAccount,
+          woodMint: woodMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+    await setPaused(PAUSE_CHOPPING);
+    await expect(chop(1)).rejects.toThrow(/GamePaused/);
+
+    await setPaused(0);
+    await chop(2);
+
+    expect(await tokenBalance(context, playerWoodAccount)).toEqual(1);
+  });
+});
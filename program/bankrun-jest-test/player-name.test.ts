@@ -0,0 +1,92 @@
+import * as anchor from "@coral-xyz/anchor";
+import { findPda, fundedKeypair, initPlayer, setupGame } from "./helpers";
+
+describe("Player names", () => {
+  test("Names are unique ignoring case and released on rename", async () => {
+    const { context, program, payer } = await setupGame();
+    const other = fundedKeypair(context);
+
+    const playerPDA = await initPlayer(program, payer);
+    const otherPDA = await initPlayer(program, other);
+
+    const nameRegistry = (name: string) =>
+      findPda(program, Buffer.from("name"), Buffer.from(name.toLowerCase()));
+
+    await program.methods
+      .setPlayerName("CookieMonster")
+      .accountsStrict({
+        player: payer.publicKey,
+        playerData: playerPDA,
+        nameRegistry: nameRegistry("CookieMonster"),
+        oldNameRegistry: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    // Same name in a different case is taken
+    await expect(
+      program.methods
+        .setPlayerName("cookiemonster")
+        .accountsStrict({
+          player: other.publicKey,
+          playerData: otherPDA,
+          nameRegistry: nameRegistry("cookiemonster"),
+          oldNameRegistry: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([other])
+        .rpc()
+    ).rejects.toThrow(/NameTaken/);
+
+    // Renaming frees the old name
+    await program.methods
+      .setPlayerName("Baker")
+      .accountsStrict({
+        player: payer.publicKey,
+        playerData: playerPDA,
+        nameRegistry: nameRegistry("Baker"),
+        oldNameRegistry: nameRegistry("CookieMonster"),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .setPlayerName("cookiemonster")
+      .accountsStrict({
+        player: other.publicKey,
+        playerData: otherPDA,
+        nameRegistry: nameRegistry("cookiemonster"),
+        oldNameRegistry: null,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([other])
+      .rpc();
+
+    const player = await program.account.playerData.fetch(playerPDA);
+    expect(player.name).toEqual("Baker");
+    const otherPlayer = await program.account.playerData.fetch(otherPDA);
+    expect(otherPlayer.name).toEqual("cookiemonster");
+  });
+
+  test("Invalid names are rejected", async () => {
+    const { program, payer } = await setupGame();
+    const playerPDA = await initPlayer(program, payer);
+
+    await expect(
+      program.methods
+        .setPlayerName("no spaces")
+        .accountsStrict({
+          player: payer.publicKey,
+          playerData: playerPDA,
+          nameRegistry: findPda(
+            program,
+            Buffer.from("name"),
+            Buffer.from("no spaces")
+          ),
+          oldNameRegistry: null,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc()
+    ).rejects.toThrow(/InvalidNameCharacters/);
+  });
+});
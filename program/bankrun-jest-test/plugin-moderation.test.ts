@@ -0,0 +1,180 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { Clock } from "solana-bankrun";
+import {
+  createPlugin,
+  findPda,
+  fundedKeypair,
+  initPlayer,
+  initPluginRegistry,
+  installPlugin,
+  setupCookieMint,
+  setupGame,
+  tokenBalance,
+} from "./helpers";
+
+describe("Plugin moderation", () => {
+  test("Only active plugins can be installed", async () => {
+    const { context, program, payer, programAdminPDA } = await setupGame();
+    setupCookieMint(context, program);
+    await initPluginRegistry(program, payer);
+    const creator = fundedKeypair(context);
+    const player = fundedKeypair(context);
+    await initPlayer(program, creator);
+    await initPlayer(program, player);
+    const pluginPDA = await createPlugin(context, program, creator);
+
+    const setListing = (listed: boolean) =>
+      program.methods
+        .setPluginListing(listed)
+        .accountsStrict({
+          owner: creator.publicKey,
+          plugin: pluginPDA,
+          nftHolderAccount: null,
+        })
+        .signers([creator])
+        .rpc();
+    const moderate = (status: any) =>
+      program.methods
+        .moderatePlugin(status)
+        .accountsStrict({
+          admin: payer.publicKey,
+          programAdmin: programAdminPDA,
+          plugin: pluginPDA,
+        })
+        .rpc();
+
+    await setListing(false);
+    await expect(
+      installPlugin(context, program, player, pluginPDA)
+    ).rejects.toThrow(/PluginNotActive/);
+
+    await setListing(true);
+    await installPlugin(context, program, player, pluginPDA);
+
+    // Creators can't undo a moderator's decision
+    await moderate({ hidden: {} });
+    await expect(setListing(true)).rejects.toThrow(/PluginModerated/);
+    const plugin = await program.account.plugin.fetch(pluginPDA);
+    expect(plugin.status).toEqual({ hidden: {} });
+  });
+
+  test("Banned plugins stop producing", async () => {
+    const { context, program, payer, programAdminPDA } = await setupGame();
+    const cookieMintPDA = setupCookieMint(context, program);
+    await initPluginRegistry(program, payer);
+    const creator = fundedKeypair(context);
+    const player = fundedKeypair(context);
+    await initPlayer(program, creator);
+    await initPlayer(program, player);
+    const pluginPDA = await createPlugin(context, program, creator);
+    const playerPluginSlot = await installPlugin(
+      context,
+      program,
+      player,
+      pluginPDA
+    );
+
+    // Let an hour of production build up
+    const clock = await context.banksClient.getClock();
+    context.setClock(
+      new Clock(
+        clock.slot,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        clock.unixTimestamp + BigInt(3600)
+      )
+    );
+
+    const claim = () =>
+      program.methods
+        .claimPluginCookies(1)
+        .accountsStrict({
+          player: player.publicKey,
+          playerData: findPda(
+            program,
+            Buffer.from("player"),
+            player.publicKey.toBuffer()
+          ),
+          playerPluginSlot,
+          plugin: pluginPDA,
+          gameConfig: findPda(program, Buffer.from("config")),
+          playerTokenAccount: getAssociatedTokenAddressSync(
+            cookieMintPDA,
+            player.publicKey
+          ),
+          cookieMint: cookieMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+        })
+        .signers([player])
+        .rpc();
+
+    // Hidden plugins keep producing
+    await program.methods
+      .moderatePlugin({ hidden: {} })
+      .accountsStrict({
+        admin: payer.publicKey,
+        programAdmin: programAdminPDA,
+        plugin: pluginPDA,
+      })
+      .rpc();
+    await claim();
+
+    context.setClock(
+      new Clock(
+        clock.slot,
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        clock.unixTimestamp + BigInt(7200)
+      )
+    );
+    await program.methods
+      .moderatePlugin({ banned: {} })
+      .accountsStrict({
+        admin: payer.publicKey,
+        programAdmin: programAdminPDA,
+        plugin: pluginPDA,
+      })
+      .rpc();
+    await expect(claim()).rejects.toThrow(/PluginBanned/);
+
+    // Closing the player doesn't pay it out either
+    const playerTokenAccount = getAssociatedTokenAddressSync(
+      cookieMintPDA,
+      player.publicKey
+    );
+    const balance = await tokenBalance(context, playerTokenAccount);
+    await program.methods
+      .closePlayer()
+      .accountsStrict({
+        player: player.publicKey,
+        playerData: findPda(
+          program,
+          Buffer.from("player"),
+          player.publicKey.toBuffer()
+        ),
+        gameConfig: findPda(program, Buffer.from("config")),
+        nameRegistry: null,
+        playerTokenAccount,
+        cookieMint: cookieMintPDA,
+        tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        [
+          playerPluginSlot,
+          findPda(
+            program,
+            Buffer.from("plugin_rating"),
+            pluginPDA.toBuffer(),
+            player.publicKey.toBuffer()
+          ),
+          pluginPDA,
+        ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+      )
+      .signers([player])
+      .rpc();
+    expect(await tokenBalance(context, playerTokenAccount)).toEqual(balance);
+  });
+});
@@ -0,0 +1,274 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { ProgramTestContext } from "solana-bankrun";
+import { Cookie } from "../target/types/cookie";
+import {
+  createPlugin,
+  findPda,
+  fundedKeypair,
+  initPlayer,
+  initPluginRegistry,
+  installPlugin,
+  setupCookieMint,
+  setupGame,
+  setupTokenAccount,
+  tokenBalance,
+} from "./helpers";
+
+// Tier 1 install cost with the default 20% creator share
+const CREATOR_REVENUE = 2;
+
+// Minting the NFT needs Metaplex, which bankrun doesn't load, so point the
+// plugin at a new mint and give `holder` its only token
+async function giveNft(
+  context: ProgramTestContext,
+  program: Program<Cookie>,
+  pluginPDA: anchor.web3.PublicKey,
+  holder: anchor.web3.PublicKey
+) {
+  const nftMint = anchor.web3.Keypair.generate().publicKey;
+  const plugin = await program.account.plugin.fetch(pluginPDA);
+  const pluginAccount = await context.banksClient.getAccount(pluginPDA);
+  const data = await program.coder.accounts.encode("plugin", {
+    ...plugin,
+    nftMint,
+  });
+  context.setAccount(pluginPDA, {
+    ...pluginAccount!,
+    data: Buffer.concat([
+      data,
+      Buffer.alloc(pluginAccount!.data.length - data.length),
+    ]),
+  });
+  return setupTokenAccount(context, nftMint, holder, 1);
+}
+
+describe("Plugin NFTs", () => {
+  test("Install revenue goes to the current NFT holder", async () => {
+    const { context, program, payer } = await setupGame();
+    const cookieMintPDA = setupCookieMint(context, program);
+    await initPluginRegistry(program, payer);
+    const creator = fundedKeypair(context);
+    const buyer = fundedKeypair(context);
+    const player = fundedKeypair(context);
+    await initPlayer(program, creator);
+    await initPlayer(program, player);
+    const pluginPDA = await createPlugin(context, program, creator);
+
+    const buyerNftAccount = await giveNft(
+      context,
+      program,
+      pluginPDA,
+      buyer.publicKey
+    );
+    const buyerCookies = setupTokenAccount(
+      context,
+      cookieMintPDA,
+      buyer.publicKey,
+      0
+    );
+
+    // The original creator no longer gets paid
+    await expect(
+      installPlugin(
+        context,
+        program,
+        player,
+        pluginPDA,
+        null,
+        null,
+        buyerNftAccount
+      )
+    ).rejects.toThrow(/WrongRevenueRecipient/);
+    await expect(
+      installPlugin(context, program, player, pluginPDA, null, buyer.publicKey)
+    ).rejects.toThrow(/MissingNftAccount/);
+
+    await installPlugin(
+      context,
+      program,
+      player,
+      pluginPDA,
+      null,
+      buyer.publicKey,
+      buyerNftAccount
+    );
+    expect(await tokenBalance(context, buyerCookies)).toEqual(CREATOR_REVENUE);
+    expect(
+      await tokenBalance(
+        context,
+        getAssociatedTokenAddressSync(cookieMintPDA, creator.publicKey)
+      )
+    ).toEqual(1_000_000 - 1000);
+  });
+
+  test("The NFT holder manages the plugin instead of the creator", async () => {
+    const { context, program, payer } = await setupGame();
+    setupCookieMint(context, program);
+    await initPluginRegistry(program, payer);
+    const creator = fundedKeypair(context);
+    const buyer = fundedKeypair(context);
+    await initPlayer(program, creator);
+    const pluginPDA = await createPlugin(context, program, creator);
+    const buyerNftAccount = await giveNft(
+      context,
+      program,
+      pluginPDA,
+      buyer.publicKey
+    );
+
+    const setListing = (
+      signer: anchor.web3.Keypair,
+      nftHolderAccount: anchor.web3.PublicKey | null
+    ) =>
+      program.methods
+        .setPluginListing(false)
+        .accountsStrict({
+          owner: signer.publicKey,
+          plugin: pluginPDA,
+          nftHolderAccount,
+        })
+        .signers([signer])
+        .rpc();
+    const update = (
+      signer: anchor.web3.Keypair,
+      nftHolderAccount: anchor.web3.PublicKey | null,
+      metadataUri: string | null = "https://example.com/new.json"
+    ) =>
+      program.methods
+        .updatePlugin(metadataUri, metadataUri ? null : 3000)
+        .accountsStrict({
+          owner: signer.publicKey,
+          gameConfig: findPda(program, Buffer.from("config")),
+          plugin: pluginPDA,
+          nftHolderAccount,
+          pluginNftMint: null,
+          nftMetadata: null,
+          tokenMetadataProgram: null,
+        })
+        .signers([signer])
+        .rpc();
+
+    await expect(setListing(creator, null)).rejects.toThrow(
+      /MissingNftAccount/
+    );
+    await expect(setListing(creator, buyerNftAccount)).rejects.toThrow(
+      /Unauthorized/
+    );
+    await expect(update(creator, buyerNftAccount)).rejects.toThrow(
+      /Unauthorized/
+    );
+
+    // An account of the right mint that no longer holds the NFT proves nothing
+    const { nftMint } = await program.account.plugin.fetch(pluginPDA);
+    const creatorNftAccount = setupTokenAccount(
+      context,
+      nftMint!,
+      creator.publicKey,
+      0
+    );
+    await expect(setListing(creator, creatorNftAccount)).rejects.toThrow(
+      /NotNftHolder/
+    );
+
+    // A new URI goes to the NFT's metadata as well
+    await expect(update(buyer, buyerNftAccount)).rejects.toThrow(
+      /MissingNftAccount/
+    );
+
+    await setListing(buyer, buyerNftAccount);
+    await update(buyer, buyerNftAccount, null);
+    const plugin = await program.account.plugin.fetch(pluginPDA);
+    expect(plugin.status).toEqual({ delisted: {} });
+    expect(plugin.pendingCreatorShareBps).toEqual(3000);
+  });
+
+  test("Ratings and closing follow the NFT holder", async () => {
+    const { context, program, payer, gameConfigPDA } = await setupGame();
+    const cookieMintPDA = setupCookieMint(context, program);
+    await initPluginRegistry(program, payer);
+    const creator = fundedKeypair(context);
+    const buyer = fundedKeypair(context);
+    await initPlayer(program, creator);
+    await initPlayer(program, buyer);
+    const pluginPDA = await createPlugin(context, program, creator);
+    const buyerNftAccount = await giveNft(
+      context,
+      program,
+      pluginPDA,
+      buyer.publicKey
+    );
+    setupTokenAccount(context, cookieMintPDA, buyer.publicKey, 0);
+
+    const install = (player: anchor.web3.Keypair) =>
+      installPlugin(
+        context,
+        program,
+        player,
+        pluginPDA,
+        null,
+        buyer.publicKey,
+        buyerNftAccount
+      );
+    const ratingPDA = (rater: anchor.web3.Keypair) =>
+      findPda(
+        program,
+        Buffer.from("plugin_rating"),
+        pluginPDA.toBuffer(),
+        rater.publicKey.toBuffer()
+      );
+    const rate = (rater: anchor.web3.Keypair) =>
+      program.methods
+        .ratePlugin(5)
+        .accountsStrict({
+          player: rater.publicKey,
+          gameConfig: gameConfigPDA,
+          plugin: pluginPDA,
+          pluginRating: ratingPDA(rater),
+          nftHolderAccount: buyerNftAccount,
+        })
+        .signers([rater])
+        .rpc();
+
+    const creatorSlot = await install(creator);
+    await install(buyer);
+
+    // The plugin is the buyer's now, not the creator's
+    await expect(rate(buyer)).rejects.toThrow(/CannotRateOwnPlugin/);
+    await rate(creator);
+
+    // The creator can leave while the buyer still uses the plugin
+    await program.methods
+      .closePlayer()
+      .accountsStrict({
+        player: creator.publicKey,
+        playerData: findPda(
+          program,
+          Buffer.from("player"),
+          creator.publicKey.toBuffer()
+        ),
+        gameConfig: gameConfigPDA,
+        nameRegistry: null,
+        playerTokenAccount: getAssociatedTokenAddressSync(
+          cookieMintPDA,
+          creator.publicKey
+        ),
+        cookieMint: cookieMintPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        [creatorSlot, ratingPDA(creator), pluginPDA, buyerNftAccount].map(
+          (pubkey) => ({ pubkey, isSigner: false, isWritable: true })
+        )
+      )
+      .signers([creator])
+      .rpc();
+
+    const { activeInstalls } = await program.account.plugin.fetch(pluginPDA);
+    expect(activeInstalls.toNumber()).toEqual(1);
+  });
+});
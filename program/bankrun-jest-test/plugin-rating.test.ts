@@ -0,0 +1,73 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  createPlugin,
+  findPda,
+  fundedKeypair,
+  initPlayer,
+  initPluginRegistry,
+  installPlugin,
+  setupCookieMint,
+  setupGame,
+} from "./helpers";
+
+describe("Plugin ratings", () => {
+  test("Players who used a plugin can rate it", async () => {
+    const { context, program, payer } = await setupGame();
+    setupCookieMint(context, program);
+    await initPluginRegistry(program, payer);
+    const creator = fundedKeypair(context);
+    const player = fundedKeypair(context);
+    const stranger = fundedKeypair(context);
+    for (const keypair of [creator, player, stranger]) {
+      await initPlayer(program, keypair);
+    }
+    const first = await createPlugin(context, program, creator);
+    const second = await createPlugin(context, program, creator);
+    const third = await createPlugin(context, program, creator);
+
+    const rate = (
+      rater: anchor.web3.Keypair,
+      plugin: anchor.web3.PublicKey,
+      rating: number
+    ) =>
+      program.methods
+        .ratePlugin(rating)
+        .accountsStrict({
+          player: rater.publicKey,
+          gameConfig: findPda(program, Buffer.from("config")),
+          plugin,
+          pluginRating: findPda(
+            program,
+            Buffer.from("plugin_rating"),
+            plugin.toBuffer(),
+            rater.publicKey.toBuffer()
+          ),
+          nftHolderAccount: null,
+        })
+        .signers([rater])
+        .rpc();
+
+    // Only installing the plugin creates the rating account
+    await expect(rate(stranger, first, 5)).rejects.toThrow(
+      /AccountNotInitialized/
+    );
+
+    await installPlugin(context, program, player, first);
+    await expect(rate(player, first, 6)).rejects.toThrow(/InvalidRating/);
+    await rate(player, first, 4);
+
+    // Still counts as used after it was replaced, however long ago
+    await installPlugin(context, program, player, second, first);
+    await installPlugin(context, program, player, third, second);
+    await rate(player, first, 2);
+
+    await installPlugin(context, program, creator, first);
+    await expect(rate(creator, first, 5)).rejects.toThrow(
+      /CannotRateOwnPlugin/
+    );
+
+    const plugin = await program.account.plugin.fetch(first);
+    expect(plugin.ratingCount.toNumber()).toEqual(1);
+    expect(plugin.ratingSum.toNumber()).toEqual(2);
+  });
+});
@@ -0,0 +1,114 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  createPlugin,
+  findPda,
+  fundedKeypair,
+  initPlayer,
+  initPluginRegistry,
+  setupCookieMint,
+  setupGame,
+} from "./helpers";
+
+describe("Plugin registry", () => {
+  test("Plugins from different creators get distinct IDs", async () => {
+    const { context, program, payer } = await setupGame();
+    setupCookieMint(context, program);
+    const pluginRegistryPDA = await initPluginRegistry(program, payer);
+
+    const alice = fundedKeypair(context);
+    const bob = fundedKeypair(context);
+    await initPlayer(program, alice);
+    await initPlayer(program, bob);
+
+    // Both are each creator's first plugin
+    const first = await program.account.plugin.fetch(
+      await createPlugin(context, program, alice)
+    );
+    const second = await program.account.plugin.fetch(
+      await createPlugin(context, program, bob)
+    );
+    expect(first.pluginId.toNumber()).toEqual(1);
+    expect(second.pluginId.toNumber()).toEqual(2);
+    expect(second.creator.toBase58()).toEqual(bob.publicKey.toBase58());
+
+    const registry = await program.account.pluginRegistry.fetch(
+      pluginRegistryPDA
+    );
+    expect(registry.nextPluginId.toNumber()).toEqual(3);
+    expect(registry.totalPlugins.toNumber()).toEqual(2);
+  });
+
+  test("Registry must start above existing plugin IDs", async () => {
+    const { program, payer } = await setupGame();
+
+    await expect(initPluginRegistry(program, payer, 0)).rejects.toThrow(
+      /InvalidConfig/
+    );
+  });
+
+  test("Existing plugins are counted once", async () => {
+    const { context, program, payer, programAdminPDA } = await setupGame();
+
+    // A plugin from before the registry
+    const [plugin, bump] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("plugin"),
+        new anchor.BN(1).toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const data = await program.coder.accounts.encode("plugin", {
+      version: 1,
+      pluginId: new anchor.BN(1),
+      creator: payer.publicKey,
+      tier: 1,
+      metadataUri: "https://example.com/plugin.json",
+      totalInstalls: new anchor.BN(0),
+      creatorEarnings: new anchor.BN(0),
+      createdAt: new anchor.BN(0),
+      creatorShareBps: 2000,
+      burnShareBps: 8000,
+      bump,
+      pendingCreatorShareBps: 0,
+      shareChangeAt: new anchor.BN(0),
+      status: { active: {} },
+      ratingSum: new anchor.BN(0),
+      ratingCount: new anchor.BN(0),
+      nftMint: null,
+      activeInstalls: new anchor.BN(0),
+    });
+    context.setAccount(plugin, {
+      lamports: 1_000_000_000,
+      data,
+      owner: program.programId,
+      executable: false,
+    });
+
+    const pluginRegistryPDA = findPda(program, Buffer.from("plugin_registry"));
+    const init = (existing: anchor.web3.PublicKey[]) =>
+      program.methods
+        .initPluginRegistry(new anchor.BN(2))
+        .accountsStrict({
+          admin: payer.publicKey,
+          programAdmin: programAdminPDA,
+          pluginRegistry: pluginRegistryPDA,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts(
+          existing.map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: false,
+          }))
+        )
+        .rpc();
+
+    await expect(init([plugin, plugin])).rejects.toThrow(/InvalidAccount/);
+    await init([plugin]);
+
+    const registry = await program.account.pluginRegistry.fetch(
+      pluginRegistryPDA
+    );
+    expect(registry.totalPlugins.toNumber()).toEqual(1);
+  });
+});
@@ -0,0 +1,87 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Clock } from "solana-bankrun";
+import {
+  createPlugin,
+  findPda,
+  fundedKeypair,
+  initPlayer,
+  initPluginRegistry,
+  setupCookieMint,
+  setupGame,
+} from "./helpers";
+
+// Must match PLUGIN_SHARE_CHANGE_NOTICE_SECONDS in constants.rs
+const SHARE_CHANGE_NOTICE_SECONDS = 7 * 86400;
+
+describe("Plugin updates", () => {
+  test("Metadata changes at once, share changes after the notice period", async () => {
+    const { context, program, payer } = await setupGame();
+    setupCookieMint(context, program);
+    await initPluginRegistry(program, payer);
+    const creator = fundedKeypair(context);
+    await initPlayer(program, creator);
+    const pluginPDA = await createPlugin(context, program, creator, 1, 2000);
+
+    const setTime = async (unixTimestamp: number) => {
+      const clock = await context.banksClient.getClock();
+      context.setClock(
+        new Clock(
+          clock.slot,
+          clock.epochStartTimestamp,
+          clock.epoch,
+          clock.leaderScheduleEpoch,
+          BigInt(unixTimestamp)
+        )
+      );
+    };
+
+    const update = (
+      signer: anchor.web3.Keypair,
+      metadataUri: string | null,
+      creatorShareBps: number | null
+    ) =>
+      program.methods
+        .updatePlugin(metadataUri, creatorShareBps)
+        .accountsStrict({
+          owner: signer.publicKey,
+          gameConfig: findPda(program, Buffer.from("config")),
+          plugin: pluginPDA,
+          nftHolderAccount: null,
+          pluginNftMint: null,
+          nftMetadata: null,
+          tokenMetadataProgram: null,
+        })
+        .signers([signer])
+        .rpc();
+
+    await setTime(1_000_000);
+    await update(creator, "https://example.com/fixed.json", 5000);
+
+    let plugin = await program.account.plugin.fetch(pluginPDA);
+    expect(plugin.metadataUri).toEqual("https://example.com/fixed.json");
+    expect(plugin.creatorShareBps).toEqual(2000);
+    expect(plugin.pendingCreatorShareBps).toEqual(5000);
+    expect(plugin.shareChangeAt.toNumber()).toEqual(
+      1_000_000 + SHARE_CHANGE_NOTICE_SECONDS
+    );
+
+    // Still pending just before the notice period ends
+    await setTime(1_000_000 + SHARE_CHANGE_NOTICE_SECONDS - 1);
+    await update(creator, null, null);
+    plugin = await program.account.plugin.fetch(pluginPDA);
+    expect(plugin.creatorShareBps).toEqual(2000);
+
+    await setTime(1_000_000 + SHARE_CHANGE_NOTICE_SECONDS);
+    await update(creator, null, null);
+    plugin = await program.account.plugin.fetch(pluginPDA);
+    expect(plugin.creatorShareBps).toEqual(5000);
+    expect(plugin.burnShareBps).toEqual(5000);
+    expect(plugin.shareChangeAt.toNumber()).toEqual(0);
+
+    // Only the creator can edit
+    const stranger = fundedKeypair(context);
+    await expect(update(stranger, "https://evil.example", null)).rejects.toThrow(
+      /Unauthorized/
+    );
+  });
+});
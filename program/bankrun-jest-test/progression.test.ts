@@ -0,0 +1,53 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  GAME_DATA_SEED,
+  findPda,
+  initPlayer,
+  setupGame,
+  setupWoodMint,
+} from "./helpers";
+
+describe("XP and levels", () => {
+  test("Chopping awards XP and crosses the level 2 threshold", async () => {
+    const { context, program, payer, gameConfigPDA } = await setupGame();
+    const woodMintPDA = setupWoodMint(context, program);
+    const playerPDA = await initPlayer(program, payer);
+
+    let player = await program.account.playerData.fetch(playerPDA);
+    expect(player.level).toEqual(1);
+
+    // Default config: 2 XP per chop, level 2 at 100 XP
+    for (let i = 0; i < 50; i++) {
+      await program.methods
+        .chopTree(GAME_DATA_SEED, i + 1, 1)
+        .accountsStrict({
+          player: playerPDA,
+          sessionToken: null,
+          signer: payer.publicKey,
+          gameData: findPda(program, Buffer.from(GAME_DATA_SEED)),
+          gameConfig: gameConfigPDA,
+          playerAuthority: payer.publicKey,
+          playerWoodAccount: getAssociatedTokenAddressSync(
+            woodMintPDA,
+            payer.publicKey
+          ),
+          woodMint: woodMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      if (i === 48) {
+        player = await program.account.playerData.fetch(playerPDA);
+        expect(player.xp.toNumber()).toEqual(98);
+        expect(player.level).toEqual(1);
+      }
+    }
+
+    player = await program.account.playerData.fetch(playerPDA);
+    expect(player.xp.toNumber()).toEqual(100);
+    expect(player.level).toEqual(2);
+  });
+});
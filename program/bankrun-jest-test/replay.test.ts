@@ -0,0 +1,45 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  GAME_DATA_SEED,
+  findPda,
+  initPlayer,
+  setupGame,
+  setupWoodMint,
+} from "./helpers";
+
+describe("Replay protection", () => {
+  test("Chops with an old counter are rejected", async () => {
+    const { context, program, payer, gameConfigPDA } = await setupGame();
+    const woodMintPDA = setupWoodMint(context, program);
+    const playerPDA = await initPlayer(program, payer);
+
+    const chop = (counter: number) =>
+      program.methods
+        .chopTree(GAME_DATA_SEED, counter, 1)
+        .accountsStrict({
+          player: playerPDA,
+          sessionToken: null,
+          signer: payer.publicKey,
+          gameData: findPda(program, Buffer.from(GAME_DATA_SEED)),
+          gameConfig: gameConfigPDA,
+          playerAuthority: payer.publicKey,
+          playerWoodAccount: getAssociatedTokenAddressSync(
+            woodMintPDA,
+            payer.publicKey
+          ),
+          woodMint: woodMintPDA,
+          tokenProgram: anchor.utils.token.TOKEN_PROGRAM_ID,
+          associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+
+    await chop(5);
+    await expect(chop(3)).rejects.toThrow(/StaleNonce/);
+    await chop(6);
+
+    const player = await program.account.playerData.fetch(playerPDA);
+    expect(player.lastId).toEqual(6);
+  });
+});
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use cookie::cpi::accounts::OnClick;
+use cookie::program::Cookie;
+
+declare_id!("BkD61Yube6KgJZZwzCWRqbiPJ3PtaG9RvZJjrEWR72VA");
+
+/// Only used by the tests: wraps `on_click` in a CPI, the way an on-chain
+/// click farm would, so they can check that the cookie program rejects it.
+#[program]
+pub mod click_proxy {
+    use super::*;
+
+    pub fn click(ctx: Context<Click>, counter: u16) -> Result<()> {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.cookie_program.to_account_info(),
+            OnClick {
+                session_token: None,
+                player: ctx.accounts.player.to_account_info(),
+                game_config: ctx.accounts.game_config.to_account_info(),
+                signer: ctx.accounts.signer.to_account_info(),
+                player_authority: ctx.accounts.player_authority.to_account_info(),
+                player_token_account: ctx.accounts.player_token_account.to_account_info(),
+                reward_token_mint: ctx.accounts.reward_token_mint.to_account_info(),
+                instructions: ctx.accounts.instructions.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        );
+        cookie::cpi::on_click(cpi_ctx, counter)
+    }
+}
+
+/// The accounts of `on_click`, all checked by the cookie program
+#[derive(Accounts)]
+pub struct Click<'info> {
+    /// CHECK: Checked by the cookie program
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+    /// CHECK: Checked by the cookie program
+    pub game_config: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    /// CHECK: Checked by the cookie program
+    #[account(mut)]
+    pub player_authority: UncheckedAccount<'info>,
+    /// CHECK: Checked by the cookie program
+    #[account(mut)]
+    pub player_token_account: UncheckedAccount<'info>,
+    /// CHECK: Checked by the cookie program
+    #[account(mut)]
+    pub reward_token_mint: UncheckedAccount<'info>,
+    /// CHECK: Checked by the cookie program
+    pub instructions: UncheckedAccount<'info>,
+    /// CHECK: Checked by the cookie program
+    pub token_program: UncheckedAccount<'info>,
+    /// CHECK: Checked by the cookie program
+    pub associated_token_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub cookie_program: Program<'info, Cookie>,
+}
@@ -1,6 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+
+pub const MAX_WOOD_PER_TREE: u64 = 100000; // Default tree HP
+pub const FELLING_BONUS_WOOD: u64 = 1000; // Extra wood for the final chop
+
+// Player names (a name is also a PDA seed, so at most 32 bytes)
+pub const MIN_NAME_LEN: usize = 3;
+pub const MAX_NAME_LEN: usize = 32;
+
+// Defaults for the on-chain GameConfig (see `GameConfig::set_defaults`)
 pub const TIME_TO_REFILL_ENERGY: i64 = 60;
 pub const MAX_ENERGY: u64 = 100;
-pub const MAX_WOOD_PER_TREE: u64 = 100000;
+pub const CLICK_ENERGY_COST: u64 = 5;
+
+// Progression defaults
+pub const XP_PER_CLICK: u64 = 1;
+pub const XP_PER_CHOP: u64 = 2;
+pub const XP_PER_CLAIM: u64 = 5;
+pub const LEVEL_XP_BASE: u64 = 100; // Level 2 at 100 XP, level 3 at 400, level 4 at 900...
+pub const MAX_LEVEL: u8 = 50;
+pub const LEVELS_PER_TIER: u8 = 1; // Tier N needs level N
+pub const MAX_ENERGY_PER_LEVEL: u64 = 5;
+
+// Click power default
+pub const CLICK_POWER_PER_TIER: u64 = 1; // Each occupied tier adds tier * this
+
+// Combo defaults
+pub const COMBO_WINDOW_SECONDS: i64 = 3; // Combo resets if no click for this long
+pub const COMBO_STEP_CLICKS: u32 = 10; // Multiplier goes up every 10 combo clicks
+pub const COMBO_STEP_BONUS_BPS: u32 = 1000; // +10% per step
+pub const MAX_COMBO_MULTIPLIER_BPS: u32 = 30000; // Capped at 3x
+
+// Chopping and axe defaults
+pub const CHOP_ENERGY_COST: u64 = 1;
+pub const WOOD_PER_AXE_LEVEL: u64 = 1; // Each axe level adds 1 wood per chop
+pub const AXE_UPGRADE_BASE_COST: u64 = 100; // Axe level 1 costs 100 cookies, level 2 costs 200...
+pub const MAX_AXE_LEVEL: u8 = 10;
+
+// Click rate limit defaults (slots are ~400ms)
+pub const CLICK_RATE_WINDOW_SLOTS: u64 = 25; // ~10 second window
+pub const MAX_CLICKS_PER_WINDOW: u32 = 200; // ~20 clicks per second, more than a human can do
+
+// Golden cookie defaults (slots are ~400ms)
+pub const GOLDEN_COOKIE_INTERVAL_SLOTS: u64 = 9000; // A golden cookie about every hour
+pub const GOLDEN_COOKIE_LIFETIME_SLOTS: u64 = 150; // Claimable for about a minute
+pub const GOLDEN_COOKIE_LUMP_SUM: u64 = 500;
+pub const GOLDEN_COOKIE_FRENZY_CHANCE: u8 = 50; // Percent
+pub const FRENZY_DURATION_SECONDS: i64 = 77;
+pub const FRENZY_MULTIPLIER_BPS: u32 = 70000; // 7x production
+
+// Daily reward defaults
+pub const SECONDS_PER_DAY: i64 = 86400;
+pub const DAILY_REWARD_BASE: u64 = 100; // Cookies on the first day of a streak
+pub const DAILY_REWARD_PER_STREAK_DAY: u64 = 50; // Extra cookies per consecutive day
+pub const MAX_DAILY_STREAK_BONUS_DAYS: u16 = 7; // Day 7 and later pay 400 cookies
+
+// Pause flags for `GameConfig::paused` (one bit per instruction family)
+pub const PAUSE_CLICKING: u8 = 1 << 0; // on_click
+pub const PAUSE_CHOPPING: u8 = 1 << 1; // chop_tree, upgrade_axe
+pub const PAUSE_PLUGIN_ECONOMY: u8 = 1 << 2; // unlock/install/claim/uninstall
+pub const PAUSE_PLUGIN_CREATION: u8 = 1 << 3; // create_plugin
+pub const PAUSE_GOLDEN_COOKIE: u8 = 1 << 4; // claim_golden_cookie
+pub const PAUSE_DAILY_REWARD: u8 = 1 << 5; // claim_daily_reward
+pub const PAUSE_CRAFTING: u8 = 1 << 6; // craft
+pub const PAUSE_ALL: u8 = PAUSE_CLICKING
+    | PAUSE_CHOPPING
+    | PAUSE_PLUGIN_ECONOMY
+    | PAUSE_PLUGIN_CREATION
+    | PAUSE_GOLDEN_COOKIE
+    | PAUSE_DAILY_REWARD
+    | PAUSE_CRAFTING;
 
 // Plugin System Constants
 pub const PLUGIN_BASE_PRODUCTION_RATE: u64 = 500; // Tier 1 produces 500 cookies/hour (100x BOOST FOR TESTING! Real: 5/hr)
@@ -9,6 +80,7 @@ pub const PLUGIN_INSTALL_BASE_COST: u64 = 10; // Base cost to install a plugin
 pub const PLUGIN_UNLOCK_BASE_COST: u64 = 10; // Base cost to unlock a tier
 pub const CREATOR_SHARE_BPS: u16 = 2000; // 20% to creator (basis points)
 pub const BURN_SHARE_BPS: u16 = 8000; // 80% burned (basis points)
+pub const PLUGIN_SHARE_CHANGE_NOTICE_SECONDS: i64 = 7 * SECONDS_PER_DAY; // Delay before a new creator share applies
 
 /// Calculate max accumulation hours for a tier (1 hour → 24 hours)
 /// Tiers 1-3: Exponential (1, 2, 4)
@@ -27,42 +99,75 @@ pub fn get_max_accumulation_hours(tier: u8) -> i64 {
 /// Calculate production rate (cookies per hour) for a tier
 /// Formula: BASE_RATE * (tier^1.8)
 /// Tier 1: 10 CPH, Tier 10: 631 CPH
-pub fn calculate_production_rate(tier: u8) -> u64 {
+pub fn calculate_production_rate(config: &GameConfig, tier: u8) -> u64 {
     let tier_f64 = tier as f64;
-    let rate = (PLUGIN_BASE_PRODUCTION_RATE as f64) * tier_f64.powf(1.8);
+    let rate = (config.base_production_rate as f64) * tier_f64.powf(1.8);
     rate as u64
 }
 
 /// Calculate creation cost for a plugin
 /// Formula: BASE_COST * (tier^2)
 /// Tier 1: 100, Tier 10: 10,000
-pub fn calculate_creation_cost(tier: u8) -> u64 {
-    PLUGIN_CREATION_BASE_COST * (tier as u64).pow(2)
+pub fn calculate_creation_cost(config: &GameConfig, tier: u8) -> Result<u64> {
+    let cost = config
+        .creation_base_cost
+        .checked_mul((tier as u64).pow(2))
+        .ok_or(GameErrorCode::Overflow)?;
+    Ok(cost)
 }
 
 /// Calculate install cost for a plugin
 /// Formula: BASE_COST * (tier^1.5)
 /// Tier 1: 10, Tier 10: 316
-pub fn calculate_install_cost(tier: u8) -> u64 {
+pub fn calculate_install_cost(config: &GameConfig, tier: u8) -> u64 {
     let tier_f64 = tier as f64;
-    let cost = (PLUGIN_INSTALL_BASE_COST as f64) * tier_f64.powf(1.5);
+    let cost = (config.install_base_cost as f64) * tier_f64.powf(1.5);
     cost as u64
 }
 
 /// Calculate tier unlock cost
 /// Formula: BASE_COST * 2^(tier-1)
 /// Tier 1: 0 (free), Tier 2: 10, Tier 10: 5,120
-pub fn calculate_unlock_cost(tier: u8) -> u64 {
+pub fn calculate_unlock_cost(config: &GameConfig, tier: u8) -> Result<u64> {
     if tier == 1 {
-        return 0; // Tier 1 is always unlocked
+        return Ok(0); // Tier 1 is always unlocked
     }
-    PLUGIN_UNLOCK_BASE_COST * 2u64.pow((tier - 1) as u32)
+    let cost = config
+        .unlock_base_cost
+        .checked_mul(2u64.pow((tier - 1) as u32))
+        .ok_or(GameErrorCode::Overflow)?;
+    Ok(cost)
+}
+
+/// Calculate the cookie cost of upgrading the axe to `level`
+/// Formula: BASE_COST * 2^(level-1)
+/// Level 1: 100, Level 10: 51,200
+pub fn calculate_axe_upgrade_cost(config: &GameConfig, level: u8) -> u64 {
+    config
+        .axe_upgrade_base_cost
+        .saturating_mul(2u64.saturating_pow(level.saturating_sub(1) as u32))
+}
+
+/// Calculate cookies minted per click from the occupied plugin tiers
+/// Formula: 1 + sum(tier * CLICK_POWER_PER_TIER) over occupied tiers
+/// No plugins: 1, all 10 tiers: 56 (with the default of 1 per tier)
+pub fn calculate_click_power(config: &GameConfig, installed_tiers: u16) -> u64 {
+    let tier_sum: u64 = (1..=10u8)
+        .filter(|tier| installed_tiers & (1u16 << (tier - 1)) != 0)
+        .map(|tier| tier as u64)
+        .sum();
+    1 + tier_sum.saturating_mul(config.click_power_per_tier)
 }
 
 /// Calculate accumulated cookies based on time delta
 /// Returns cookies accumulated (capped at max hours for tier)
-pub fn calculate_accumulated_cookies(tier: u8, last_claim: i64, current_time: i64) -> u64 {
-    let production_per_hour = calculate_production_rate(tier);
+pub fn calculate_accumulated_cookies(
+    config: &GameConfig,
+    tier: u8,
+    last_claim: i64,
+    current_time: i64,
+) -> u64 {
+    let production_per_hour = calculate_production_rate(config, tier);
     let seconds_elapsed = current_time.saturating_sub(last_claim);
     let max_seconds = get_max_accumulation_hours(tier) * 3600;
     let seconds_capped = seconds_elapsed.min(max_seconds);
@@ -72,3 +177,26 @@ pub fn calculate_accumulated_cookies(tier: u8, last_claim: i64, current_time: i6
     let accumulated = production_per_second * seconds_capped as f64;
     accumulated as u64
 }
+
+/// Calculate the extra cookies a frenzy adds on top of `calculate_accumulated_cookies`
+/// Only the part of the claimed window that overlaps the frenzy is boosted
+pub fn calculate_frenzy_bonus(
+    config: &GameConfig,
+    tier: u8,
+    last_claim: i64,
+    current_time: i64,
+    frenzy_start: i64,
+    frenzy_end: i64,
+    multiplier_bps: u32,
+) -> u64 {
+    let max_seconds = get_max_accumulation_hours(tier) * 3600;
+    let window_start = last_claim.max(current_time.saturating_sub(max_seconds));
+    let overlap = current_time.min(frenzy_end) - window_start.max(frenzy_start);
+    if overlap <= 0 {
+        return 0;
+    }
+
+    let production_per_second = calculate_production_rate(config, tier) as f64 / 3600.0;
+    let extra_bps = multiplier_bps.saturating_sub(10000) as f64;
+    (production_per_second * overlap as f64 * extra_bps / 10000.0) as u64
+}
@@ -26,4 +26,98 @@ pub enum GameErrorCode {
     NothingToClaim,
     #[msg("Arithmetic overflow")]
     Overflow,
+    #[msg("Plugin account for the occupied slot is missing")]
+    MissingPluginAccount,
+    #[msg("A plugin created by this player still has active installs")]
+    PluginHasActiveInstalls,
+    #[msg("Plugin is not listed for install")]
+    PluginNotActive,
+    #[msg("Plugin status was set by a moderator")]
+    PluginModerated,
+    #[msg("Plugin is banned")]
+    PluginBanned,
+    #[msg("Rating must be between 1 and 5")]
+    InvalidRating,
+    #[msg("Only players who installed this plugin can rate it")]
+    PluginNotUsed,
+    #[msg("All of the player's plugin ratings must be passed")]
+    MissingRatingAccount,
+    #[msg("Creators can't rate their own plugins")]
+    CannotRateOwnPlugin,
+    #[msg("All plugin NFT accounts must be passed together")]
+    MissingNftAccount,
+    #[msg("Revenue must go to the plugin NFT holder")]
+    WrongRevenueRecipient,
+    #[msg("Token account doesn't hold the plugin NFT")]
+    NotNftHolder,
+
+    // Config errors
+    #[msg("Invalid game config")]
+    InvalidConfig,
+    #[msg("Signer is not the admin")]
+    Unauthorized,
+    #[msg("This part of the game is paused")]
+    GamePaused,
+
+    // Migration errors
+    #[msg("Account is not a migratable game account")]
+    InvalidAccount,
+    #[msg("Account is already on the current layout")]
+    AlreadyMigrated,
+    #[msg("Account has an old layout, run migrate_account first")]
+    AccountNotMigrated,
+    #[msg("No wood left to convert into tokens")]
+    NoWoodToMigrate,
+
+    // Anti-bot errors
+    #[msg("Too many clicks, slow down")]
+    ClickRateExceeded,
+    #[msg("Clicking is not allowed through CPI")]
+    CpiNotAllowed,
+
+    // Daily reward errors
+    #[msg("Daily reward already claimed today")]
+    DailyRewardAlreadyClaimed,
+
+    // Crafting errors
+    #[msg("Invalid recipe")]
+    InvalidRecipe,
+    #[msg("This recipe is disabled")]
+    RecipeDisabled,
+    #[msg("Item recipes need the player item account")]
+    MissingItemAccount,
+
+    // Golden cookie errors
+    #[msg("No golden cookie is live right now")]
+    NoGoldenCookie,
+    #[msg("This golden cookie was already claimed")]
+    GoldenCookieClaimed,
+
+    // Player name errors
+    #[msg("Name must be 3-32 characters")]
+    InvalidNameLength,
+    #[msg("Name may only contain letters, digits, '_' and '-'")]
+    InvalidNameCharacters,
+    #[msg("Name is already taken")]
+    NameTaken,
+    #[msg("The current name registry account must be passed when renaming")]
+    OldNameRequired,
+
+    // Progression errors
+    #[msg("Level too low for this tier")]
+    LevelTooLow,
+
+    // Click and chop errors
+    #[msg("Click batch is empty or larger than the click rate limit")]
+    InvalidClickCount,
+    #[msg("Chop count must be at least 1")]
+    InvalidChopCount,
+    #[msg("Axe is already at the max level")]
+    MaxAxeLevel,
+    #[msg("Counter must be greater than the last one used")]
+    StaleNonce,
+
+    // Account closing errors
+    #[msg("Every occupied plugin slot must be passed")]
+    MissingSlotAccount,
 }
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::state::plugin::PluginStatus;
+use crate::state::recipe::RecipeOutput;
+
+/// Emitted on every `on_click` / `on_click_batch` so clients can show combo effects
+#[event]
+pub struct ClickEvent {
+    pub player: Pubkey,
+    /// Clicks in this transaction
+    pub clicks: u64,
+    /// Cookies minted in this transaction
+    pub cookies: u64,
+    /// Current combo after these clicks
+    pub combo: u32,
+    /// Reward multiplier applied, in basis points (10000 = 1x)
+    pub multiplier_bps: u32,
+}
+
+/// Emitted when a player claims a golden cookie
+#[event]
+pub struct GoldenCookieClaimed {
+    pub player: Pubkey,
+    /// Spawn number of the claimed golden cookie
+    pub round: u64,
+    /// Whether the reward was a frenzy (otherwise a lump sum)
+    pub frenzy: bool,
+    /// Cookies minted (0 for a frenzy)
+    pub cookies: u64,
+    /// When the frenzy ends (0 for a lump sum)
+    pub frenzy_end: i64,
+}
+
+/// Emitted when a player claims their daily reward
+#[event]
+pub struct DailyRewardClaimed {
+    pub player: Pubkey,
+    /// Consecutive days claimed, including today
+    pub streak: u16,
+    /// Cookies minted
+    pub cookies: u64,
+}
+
+/// Emitted when a chop fells the current tree of a level
+#[event]
+pub struct TreeFelled {
+    /// Number of the tree that fell
+    pub tree_index: u64,
+    /// Player who delivered the final chop
+    pub feller: Pubkey,
+    /// Bonus wood paid to the feller
+    pub bonus_wood: u64,
+}
+
+/// Emitted when a player crafts a recipe
+#[event]
+pub struct ItemCrafted {
+    pub player: Pubkey,
+    pub recipe_id: u64,
+    /// What the player got
+    pub output: RecipeOutput,
+}
+
+/// Emitted when a creator edits a plugin
+#[event]
+pub struct PluginUpdated {
+    pub plugin_id: u64,
+    pub metadata_uri: String,
+    /// Creator share in effect now, in basis points
+    pub creator_share_bps: u16,
+    /// Share that applies at `share_change_at`
+    pub pending_creator_share_bps: u16,
+    /// When the pending share takes effect (0 = no change scheduled)
+    pub share_change_at: i64,
+}
+
+/// Emitted when a plugin is listed, delisted or moderated
+#[event]
+pub struct PluginStatusChanged {
+    pub plugin_id: u64,
+    pub status: PluginStatus,
+    /// Creator or admin who made the change
+    pub changed_by: Pubkey,
+}
+
+/// Emitted when a player rates a plugin or changes their rating
+#[event]
+pub struct PluginRated {
+    pub plugin_id: u64,
+    pub player: Pubkey,
+    /// Rating given, 1-5
+    pub rating: u8,
+    /// Plugin totals after this rating
+    pub rating_sum: u64,
+    pub rating_count: u64,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::program_admin::ProgramAdmin;
+
+/// Second step of an admin rotation, signed by the proposed admin
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let program_admin = &mut ctx.accounts.program_admin;
+    program_admin.authority = ctx.accounts.new_admin.key();
+    program_admin.pending_authority = None;
+
+    msg!("Program admin is now {}", program_admin.authority);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.pending_authority == Some(new_admin.key())
+            @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+}
@@ -1,25 +1,64 @@
 pub use crate::errors::GameErrorCode;
 pub use crate::state::game_data::GameData;
+use crate::constants::PAUSE_CHOPPING;
+use crate::events::TreeFelled;
+use crate::state::game_config::GameConfig;
 use crate::state::player_data::PlayerData;
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 use session_keys::{Session, SessionToken};
 
-pub fn chop_tree(mut ctx: Context<ChopTree>, counter: u16, amount: u64) -> Result<()> {
+/// Chop `chops` times. Each chop costs `chop_energy_cost` energy and yields
+/// more wood the better the player's axe.
+pub fn chop_tree(mut ctx: Context<ChopTree>, counter: u16, chops: u16) -> Result<()> {
     let account: &mut &mut ChopTree<'_> = &mut ctx.accounts;
-    account.player.update_energy()?;
+    account.game_config.require_not_paused(PAUSE_CHOPPING)?;
+    account.player.update_energy(&account.game_config)?;
     account.player.print()?;
 
-    if account.player.energy < amount {
-        return err!(GameErrorCode::NotEnoughEnergy);
+    let game_data_len = account.game_data.to_account_info().data_len();
+    account.game_data.init_or_require_current(game_data_len)?;
+
+    account.player.use_nonce(counter)?;
+    let amount = account.player.chop_tree(&account.game_config, chops)?;
+    let mut wood = amount;
+    let xp = account.game_config.xp_per_chop.saturating_mul(chops as u64);
+    account.player.add_xp(&account.game_config, xp)?;
+    let felled = account.game_data.on_tree_chopped(
+        &account.game_config,
+        amount,
+        account.player.authority,
+    )?;
+    if felled {
+        let bonus = account.game_config.felling_bonus_wood;
+        wood = wood.saturating_add(bonus);
+        emit!(TreeFelled {
+            tree_index: account.game_data.tree_index - 1,
+            feller: account.player.authority,
+            bonus_wood: bonus,
+        });
     }
 
-    account.player.last_id = counter;
-    account.player.chop_tree(amount)?;
-    account.game_data.on_tree_chopped(amount)?;
+    // PDA seeds and bump to "sign" for CPI
+    let seeds = b"wood";
+    let bump = ctx.bumps.wood_mint;
+    let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.wood_mint.to_account_info(),
+            to: ctx.accounts.player_wood_account.to_account_info(),
+            authority: ctx.accounts.wood_mint.to_account_info(),
+        },
+        signer,
+    );
+    mint_to(cpi_ctx, wood)?;
 
     msg!(
-        "You chopped a tree and got 1 wood. You have {} wood and {} energy left.",
-        ctx.accounts.player.wood,
+        "You chopped a tree and got {} wood. You have {} energy left.",
+        wood,
         ctx.accounts.player.energy
     );
     Ok(())
@@ -50,13 +89,41 @@ pub struct ChopTree<'info> {
     #[account(
         init_if_needed,
         payer = signer,
-        space = 1000,
+        space = GameData::LEN,
         seeds = [level_seed.as_ref()],
         bump,
     )]
     pub game_data: Account<'info, GameData>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
+
+    /// CHECK: The player's main wallet that owns the wood token account
+    #[account(address = player.authority)]
+    pub player_authority: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = wood_mint,
+        associated_token::authority = player_authority
+    )]
+    pub player_wood_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"wood"],
+        bump,
+    )]
+    pub wood_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
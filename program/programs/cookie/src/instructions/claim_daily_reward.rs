@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+use crate::constants::PAUSE_DAILY_REWARD;
+use crate::events::DailyRewardClaimed;
+use crate::state::game_config::GameConfig;
+use crate::state::player_data::PlayerData;
+
+/// Claim the daily reward once per UTC day. The reward grows with the streak
+/// of consecutive days, up to `max_daily_streak_bonus_days`.
+pub fn claim_daily_reward(ctx: Context<ClaimDailyReward>) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_DAILY_REWARD)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let streak = ctx.accounts.player_data.register_daily_claim(now)?;
+    let cookies = ctx.accounts.game_config.daily_reward(streak);
+
+    // Mint cookies to player
+    let seeds = b"reward";
+    let bump = ctx.bumps.cookie_mint;
+    let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.cookie_mint.to_account_info(),
+            to: ctx.accounts.player_token_account.to_account_info(),
+            authority: ctx.accounts.cookie_mint.to_account_info(),
+        },
+        signer,
+    );
+    mint_to(mint_ctx, cookies)?;
+
+    emit!(DailyRewardClaimed {
+        player: ctx.accounts.player.key(),
+        streak,
+        cookies,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimDailyReward<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+    )]
+    pub player_data: Account<'info, PlayerData>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Player's cookie token account (to receive cookies)
+    #[account(
+        init_if_needed,
+        payer = player,
+        associated_token::mint = cookie_mint,
+        associated_token::authority = player,
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward"],
+        bump,
+    )]
+    pub cookie_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
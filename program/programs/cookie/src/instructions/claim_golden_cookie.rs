@@ -0,0 +1,151 @@
+pub use crate::errors::GameErrorCode;
+use crate::constants::PAUSE_GOLDEN_COOKIE;
+use crate::events::GoldenCookieClaimed;
+use crate::state::game_config::GameConfig;
+use crate::state::golden_cookie::GoldenCookie;
+use crate::state::player_data::PlayerData;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use session_keys::{Session, SessionToken};
+
+/// Claim the golden cookie that is currently live. Only the first claim per
+/// spawn succeeds. The reward is either a lump sum of cookies or a production
+/// frenzy, picked from the most recent slot hash.
+pub fn claim_golden_cookie(ctx: Context<ClaimGoldenCookie>) -> Result<()> {
+    let config = &ctx.accounts.game_config;
+    config.require_not_paused(PAUSE_GOLDEN_COOKIE)?;
+
+    let clock = Clock::get()?;
+    let round = config
+        .golden_cookie_round(clock.slot)
+        .ok_or(GameErrorCode::NoGoldenCookie)?;
+
+    let golden_cookie = &mut ctx.accounts.golden_cookie;
+    require!(
+        golden_cookie.is_claimable(round),
+        GameErrorCode::GoldenCookieClaimed
+    );
+    golden_cookie.last_claimed_round = round;
+    golden_cookie.last_claimed_by = ctx.accounts.player.authority;
+    golden_cookie.total_claims = golden_cookie.total_claims.saturating_add(1);
+    golden_cookie.bump = ctx.bumps.golden_cookie;
+
+    // SlotHashes is too big to deserialize on chain, so read the newest entry
+    // directly: [len: u64][slot: u64][hash: 32 bytes]...
+    let slot_hashes_data = ctx.accounts.slot_hashes.try_borrow_data()?;
+    let recent_hash = slot_hashes_data
+        .get(16..48)
+        .ok_or(GameErrorCode::InvalidAccount)?;
+    let entropy = hashv(&[
+        recent_hash,
+        ctx.accounts.player.authority.as_ref(),
+        &round.to_le_bytes(),
+    ]);
+    let roll = entropy.to_bytes()[0] as u16 * 100 / 256;
+    let frenzy = roll < config.golden_cookie_frenzy_chance as u16;
+
+    let mut cookies = 0;
+    if frenzy {
+        ctx.accounts
+            .player
+            .start_frenzy(config, clock.unix_timestamp);
+    } else {
+        cookies = config.golden_cookie_lump_sum;
+
+        let seeds = b"reward";
+        let bump = ctx.bumps.reward_token_mint;
+        let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.reward_token_mint.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.reward_token_mint.to_account_info(),
+            },
+            signer,
+        );
+        mint_to(cpi_ctx, cookies)?;
+    }
+
+    emit!(GoldenCookieClaimed {
+        player: ctx.accounts.player.authority,
+        round,
+        frenzy,
+        cookies,
+        frenzy_end: if frenzy {
+            ctx.accounts.player.frenzy_end
+        } else {
+            0
+        },
+    });
+    Ok(())
+}
+
+#[derive(Accounts, Session)]
+pub struct ClaimGoldenCookie<'info> {
+    #[session(
+        // The ephemeral key pair signing the transaction
+        signer = signer,
+        // The authority of the user account which must have created the session
+        authority = player.authority.key()
+    )]
+    // Session Tokens are passed as optional accounts
+    pub session_token: Option<Account<'info, SessionToken>>,
+
+    #[account(
+        mut,
+        seeds = [b"player".as_ref(), player.authority.key().as_ref()],
+        bump,
+    )]
+    pub player: Account<'info, PlayerData>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    // Created by whoever claims the very first golden cookie
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = GoldenCookie::LEN,
+        seeds = [b"golden_cookie"],
+        bump,
+    )]
+    pub golden_cookie: Account<'info, GoldenCookie>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: The player's main wallet that owns the token account
+    #[account(mut, address = player.authority)]
+    pub player_authority: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = reward_token_mint,
+        associated_token::authority = player_authority
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward"],
+        bump,
+    )]
+    pub reward_token_mint: Account<'info, Mint>,
+
+    /// CHECK: Read raw for entropy, the address is checked
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -3,18 +3,36 @@ use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+use crate::state::player_data::PlayerData;
 use crate::state::player_plugin_slot::PlayerPluginSlot;
+use crate::state::plugin::Plugin;
 
 /// Claim accumulated cookies from a plugin
 pub fn claim_plugin_cookies(ctx: Context<ClaimPluginCookies>, tier: u8) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_PLUGIN_ECONOMY)?;
+
     let slot = &mut ctx.accounts.player_plugin_slot;
     
     // Check if slot has a plugin installed
     require!(slot.plugin_id != 0, GameErrorCode::NoPluginInstalled);
+
+    // Banned plugins stop producing
+    require!(
+        ctx.accounts.plugin.is_producing(),
+        GameErrorCode::PluginBanned
+    );
     
     // Calculate accumulated cookies
     let current_time = Clock::get()?.unix_timestamp;
-    let accumulated = calculate_accumulated_cookies(tier, slot.last_claim, current_time);
+    let accumulated = slot.pending_cookies(
+        &ctx.accounts.plugin,
+        &ctx.accounts.game_config,
+        &ctx.accounts.player_data,
+        current_time,
+    );
     
     require!(accumulated > 0, GameErrorCode::NothingToClaim);
     
@@ -40,6 +58,9 @@ pub fn claim_plugin_cookies(ctx: Context<ClaimPluginCookies>, tier: u8) -> Resul
         .total_claimed
         .checked_add(accumulated)
         .ok_or(GameErrorCode::Overflow)?;
+
+    let xp = ctx.accounts.game_config.xp_per_claim;
+    ctx.accounts.player_data.add_xp(&ctx.accounts.game_config, xp)?;
     
     msg!(
         "Claimed {} cookies from tier {} plugin (total: {})",
@@ -55,6 +76,13 @@ pub fn claim_plugin_cookies(ctx: Context<ClaimPluginCookies>, tier: u8) -> Resul
 pub struct ClaimPluginCookies<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+    )]
+    pub player_data: Account<'info, PlayerData>,
     
     /// Player's plugin slot for this tier
     #[account(
@@ -68,6 +96,19 @@ pub struct ClaimPluginCookies<'info> {
         has_one = player,
     )]
     pub player_plugin_slot: Account<'info, PlayerPluginSlot>,
+
+    /// Plugin currently installed in the slot
+    #[account(
+        seeds = [b"plugin", player_plugin_slot.plugin_id.to_le_bytes().as_ref()],
+        bump = plugin.bump,
+    )]
+    pub plugin: Account<'info, Plugin>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
     
     /// Player's cookie token account (to receive cookies)
     #[account(
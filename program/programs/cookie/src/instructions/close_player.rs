@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+use crate::state::player_data::PlayerData;
+use crate::state::player_name::PlayerName;
+use crate::state::player_plugin_slot::PlayerPluginSlot;
+use crate::state::plugin::Plugin;
+use crate::state::plugin_rating::PluginRating;
+
+/// Close the player account and reclaim all rent.
+///
+/// `remaining_accounts` holds the player's `PlayerPluginSlot`s to close (at
+/// least every occupied one), all of the player's `PluginRating`s, the
+/// `Plugin` installed in every occupied slot or rated by the player, and every
+/// `Plugin` the player created (all writable), plus the token account holding
+/// the NFT of each created plugin that has one. Pending cookies are settled
+/// before the slots are closed and the player's ratings are taken out of the
+/// plugins' totals. Fails if any plugin passed whose rights the player holds
+/// is still installed by someone else.
+pub fn close_player<'info>(ctx: Context<'_, '_, 'info, 'info, ClosePlayer<'info>>) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_PLUGIN_ECONOMY)?;
+
+    let player_key = ctx.accounts.player.key();
+
+    // Sort the remaining accounts into slots, ratings and plugins. Each may
+    // only appear once, or it would be counted twice.
+    let mut slots: BTreeMap<u8, Account<'info, PlayerPluginSlot>> = BTreeMap::new();
+    let mut ratings: BTreeMap<Pubkey, Account<'info, PluginRating>> = BTreeMap::new();
+    let mut plugins: BTreeMap<u64, Account<'info, Plugin>> = BTreeMap::new();
+    let mut nft_holders: BTreeMap<Pubkey, Account<'info, TokenAccount>> = BTreeMap::new();
+    for info in ctx.remaining_accounts.iter() {
+        // NFT holder accounts are only read
+        if *info.owner == Token::id() {
+            let holder = Account::<TokenAccount>::try_from(info)?;
+            require!(
+                nft_holders.insert(holder.mint, holder).is_none(),
+                GameErrorCode::InvalidAccount
+            );
+            continue;
+        }
+
+        require!(info.is_writable, GameErrorCode::InvalidAccount);
+        let discriminator: [u8; 8] = {
+            let data = info.try_borrow_data()?;
+            require!(data.len() >= 8, GameErrorCode::InvalidAccount);
+            data[..8].try_into().unwrap()
+        };
+
+        if discriminator == PlayerPluginSlot::DISCRIMINATOR {
+            let slot = Account::<PlayerPluginSlot>::try_from(info)?;
+            require_keys_eq!(slot.player, player_key, GameErrorCode::WrongAuthority);
+            require!(
+                slots.insert(slot.tier, slot).is_none(),
+                GameErrorCode::InvalidAccount
+            );
+        } else if discriminator == PluginRating::DISCRIMINATOR {
+            let rating = Account::<PluginRating>::try_from(info)?;
+            require_keys_eq!(rating.player, player_key, GameErrorCode::WrongAuthority);
+            require!(
+                ratings.insert(rating.key(), rating).is_none(),
+                GameErrorCode::InvalidAccount
+            );
+        } else if discriminator == Plugin::DISCRIMINATOR {
+            let plugin = Account::<Plugin>::try_from(info)?;
+            require!(
+                plugins.insert(plugin.plugin_id, plugin).is_none(),
+                GameErrorCode::InvalidAccount
+            );
+        } else {
+            return err!(GameErrorCode::InvalidAccount);
+        }
+    }
+
+    // Occupied slots left behind would keep their plugins' installs counted
+    let installed_tiers = ctx.accounts.player_data.installed_tiers;
+    for tier in 1..=10u8 {
+        require!(
+            installed_tiers & (1u16 << (tier - 1)) == 0 || slots.contains_key(&tier),
+            GameErrorCode::MissingSlotAccount
+        );
+    }
+
+    // Settle pending cookies and release the installed plugins
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut pending: u64 = 0;
+    for slot in slots.values() {
+        if slot.plugin_id == 0 {
+            continue;
+        }
+
+        let plugin = plugins
+            .get_mut(&slot.plugin_id)
+            .ok_or(GameErrorCode::MissingPluginAccount)?;
+        plugin.active_installs = plugin.active_installs.saturating_sub(1);
+
+        let accumulated = slot.pending_cookies(
+            plugin,
+            &ctx.accounts.game_config,
+            &ctx.accounts.player_data,
+            current_time,
+        );
+        pending = pending
+            .checked_add(accumulated)
+            .ok_or(GameErrorCode::Overflow)?;
+    }
+
+    if pending > 0 {
+        let seeds = b"reward";
+        let bump = ctx.bumps.cookie_mint;
+        let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
+
+        let mint_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.cookie_mint.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.cookie_mint.to_account_info(),
+            },
+            signer,
+        );
+        mint_to(mint_ctx, pending)?;
+
+        msg!("Claimed {} cookies before closing", pending);
+    }
+
+    // Take the player's ratings out of the plugins' totals
+    require!(
+        ratings.len() == ctx.accounts.player_data.used_plugins as usize,
+        GameErrorCode::MissingRatingAccount
+    );
+    for rating in ratings.values().filter(|rating| rating.rating != 0) {
+        let plugin = plugins
+            .values_mut()
+            .find(|plugin| plugin.key() == rating.plugin)
+            .ok_or(GameErrorCode::MissingPluginAccount)?;
+        plugin.remove_rating(rating.rating);
+    }
+
+    // Every plugin this player created has to be passed in. Those the player
+    // still holds the rights to have to be unused.
+    let mut created: u64 = 0;
+    for plugin in plugins.values() {
+        let is_creator = plugin.creator == player_key;
+        if is_creator {
+            created += 1;
+        }
+
+        let holder = plugin.nft_mint.and_then(|mint| nft_holders.get(&mint));
+        // Only the creator has to show where a plugin's NFT went
+        if !is_creator && plugin.nft_mint.is_some() && holder.is_none() {
+            continue;
+        }
+        if plugin.rights_holder(holder.map(|holder| &**holder))? == player_key {
+            require!(
+                plugin.active_installs == 0,
+                GameErrorCode::PluginHasActiveInstalls
+            );
+        }
+    }
+    let created_count = ctx
+        .accounts
+        .player_data
+        .plugin_global_counter
+        .saturating_sub(1);
+    require!(
+        created == created_count,
+        GameErrorCode::MissingPluginAccount
+    );
+
+    // Release the player's name
+    if !ctx.accounts.player_data.name.is_empty() {
+        let name_registry = ctx
+            .accounts
+            .name_registry
+            .as_ref()
+            .ok_or(GameErrorCode::OldNameRequired)?;
+        require_keys_eq!(
+            name_registry.owner,
+            player_key,
+            GameErrorCode::WrongAuthority
+        );
+        name_registry.close(ctx.accounts.player.to_account_info())?;
+    }
+
+    // Persist the plugin install counts and ratings, then close the slots and
+    // ratings
+    for plugin in plugins.values() {
+        plugin.exit(&crate::ID)?;
+    }
+    let slot_count = slots.len();
+    for slot in slots.into_values() {
+        slot.close(ctx.accounts.player.to_account_info())?;
+    }
+    for rating in ratings.into_values() {
+        rating.close(ctx.accounts.player.to_account_info())?;
+    }
+
+    msg!("Player closed along with {} plugin slots", slot_count);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClosePlayer<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+        close = player,
+    )]
+    pub player_data: Account<'info, PlayerData>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Registry entry for the player's name, required if a name is set
+    #[account(mut)]
+    pub name_registry: Option<Account<'info, PlayerName>>,
+
+    /// Player's cookie token account (to receive pending cookies)
+    #[account(
+        mut,
+        associated_token::mint = cookie_mint,
+        associated_token::authority = player,
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward"],
+        bump,
+    )]
+    pub cookie_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+
+use crate::constants::PAUSE_CRAFTING;
+use crate::errors::GameErrorCode;
+use crate::events::ItemCrafted;
+use crate::state::game_config::GameConfig;
+use crate::state::player_data::PlayerData;
+use crate::state::player_item::PlayerItem;
+use crate::state::recipe::{Recipe, RecipeOutput};
+
+/// Craft a recipe: burn its wood and cookie cost and apply its output, all in
+/// one transaction. Item recipes need the `player_item` account.
+pub fn craft(ctx: Context<Craft>, recipe_id: u64) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_CRAFTING)?;
+
+    let recipe = &ctx.accounts.recipe;
+    require!(recipe.enabled, GameErrorCode::RecipeDisabled);
+    let output = recipe.output;
+    if output.item_id().is_some() {
+        require!(
+            ctx.accounts.player_item.is_some(),
+            GameErrorCode::MissingItemAccount
+        );
+    }
+
+    if recipe.wood_cost > 0 {
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.wood_mint.to_account_info(),
+                from: ctx.accounts.player_wood_account.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        );
+        burn(burn_ctx, recipe.wood_cost)?;
+    }
+
+    if recipe.cookie_cost > 0 {
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.cookie_mint.to_account_info(),
+                from: ctx.accounts.player_cookie_account.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        );
+        burn(burn_ctx, recipe.cookie_cost)?;
+    }
+
+    let player_data = &mut ctx.accounts.player_data;
+    match output {
+        RecipeOutput::Item { item_id } => {
+            // Checked above
+            let player_item = ctx.accounts.player_item.as_mut().unwrap();
+            player_item.owner = ctx.accounts.player.key();
+            player_item.item_id = item_id;
+            player_item.amount = player_item.amount.saturating_add(1);
+            if let Some(bump) = ctx.bumps.player_item {
+                player_item.bump = bump;
+            }
+        }
+        RecipeOutput::EnergyRefill { amount } => {
+            player_data.update_energy(&ctx.accounts.game_config)?;
+            player_data.add_energy(&ctx.accounts.game_config, amount);
+        }
+        RecipeOutput::ProductionBoost {
+            multiplier_bps,
+            duration_seconds,
+        } => {
+            let now = Clock::get()?.unix_timestamp;
+            player_data.start_production_boost(multiplier_bps, duration_seconds, now);
+        }
+    }
+
+    let recipe = &mut ctx.accounts.recipe;
+    recipe.times_crafted = recipe.times_crafted.saturating_add(1);
+
+    emit!(ItemCrafted {
+        player: ctx.accounts.player.key(),
+        recipe_id,
+        output,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(recipe_id: u64)]
+pub struct Craft<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+    )]
+    pub player_data: Account<'info, PlayerData>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"recipe", recipe_id.to_le_bytes().as_ref()],
+        bump = recipe.bump,
+    )]
+    pub recipe: Account<'info, Recipe>,
+
+    /// Only needed for recipes that output an item
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PlayerItem::LEN,
+        seeds = [
+            b"item",
+            player.key().as_ref(),
+            recipe.output.item_id().unwrap_or_default().to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub player_item: Option<Account<'info, PlayerItem>>,
+
+    /// Player's wood token account (to burn the wood cost)
+    #[account(
+        mut,
+        associated_token::mint = wood_mint,
+        associated_token::authority = player,
+    )]
+    pub player_wood_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"wood"],
+        bump,
+    )]
+    pub wood_mint: Account<'info, Mint>,
+
+    /// Player's cookie token account (to burn the cookie cost)
+    #[account(
+        mut,
+        associated_token::mint = cookie_mint,
+        associated_token::authority = player,
+    )]
+    pub player_cookie_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward"],
+        bump,
+    )]
+    pub cookie_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 
+use crate::errors::GameErrorCode;
+use crate::state::program_admin::ProgramAdmin;
+
 // Import metadata types from mpl_token_metadata
 use mpl_token_metadata::instructions::{
     CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs,
@@ -29,29 +32,50 @@ pub fn create_mint(
         uses: None,
     };
 
-    // Create metadata account using mpl_token_metadata
+    create_metadata(
+        &ctx.accounts.metadata_account,
+        &ctx.accounts.reward_token_mint.to_account_info(),
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent.to_account_info(),
+        data_v2,
+        signer,
+    )
+}
+
+/// Create the metadata account of a mint PDA that is its own mint and update
+/// authority. `signer` holds the seeds of the mint PDA.
+pub(crate) fn create_metadata<'info>(
+    metadata: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    data: DataV2,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
     let metadata_infos = vec![
-        ctx.accounts.metadata_account.to_account_info(),
-        ctx.accounts.reward_token_mint.to_account_info(),
-        ctx.accounts.reward_token_mint.to_account_info(), // mint authority
-        ctx.accounts.admin.to_account_info(),
-        ctx.accounts.reward_token_mint.to_account_info(), // update authority
-        ctx.accounts.system_program.to_account_info(),
-        ctx.accounts.rent.to_account_info(),
+        metadata.clone(),
+        mint.clone(),
+        mint.clone(), // mint authority
+        payer.clone(),
+        mint.clone(), // update authority
+        system_program.clone(),
+        rent.clone(),
     ];
 
     let create_metadata_account_ix = CreateMetadataAccountV3 {
-        metadata: ctx.accounts.metadata_account.key(),
-        mint: ctx.accounts.reward_token_mint.key(),
-        mint_authority: ctx.accounts.reward_token_mint.key(),
-        payer: ctx.accounts.admin.key(),
-        update_authority: (ctx.accounts.reward_token_mint.key(), true),
-        system_program: ctx.accounts.system_program.key(),
-        rent: Some(ctx.accounts.rent.key()),
+        metadata: metadata.key(),
+        mint: mint.key(),
+        mint_authority: mint.key(),
+        payer: payer.key(),
+        update_authority: (mint.key(), true),
+        system_program: system_program.key(),
+        rent: Some(rent.key()),
     };
 
     let args = CreateMetadataAccountV3InstructionArgs {
-        data: data_v2,
+        data,
         is_mutable: true,
         collection_details: None,
     };
@@ -68,10 +92,16 @@ pub fn create_mint(
 #[derive(Accounts)]
 pub struct CreateMint<'info> {
     /// The admin account that can create the mint
-    /// TODO: Add address constraint with proper admin pubkey
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
     // The PDA is both the address of the mint account and the mint authority
     #[account(
         init,
@@ -84,7 +114,7 @@ pub struct CreateMint<'info> {
     )]
     pub reward_token_mint: Account<'info, Mint>,
 
-    ///CHECK: Using "address" constraint to validate metadata account address
+    ///CHECK: Using "seeds" constraint to validate metadata account address
     #[account(
         mut,
         seeds = [
@@ -99,6 +129,7 @@ pub struct CreateMint<'info> {
 
     pub token_program: Program<'info, Token>,
     /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
     pub token_metadata_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -1,20 +1,34 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{burn, mint_to, Burn, Mint, MintTo, Token, TokenAccount};
+use mpl_token_metadata::instructions::{
+    CreateMasterEditionV3, CreateMasterEditionV3InstructionArgs,
+};
+use mpl_token_metadata::types::DataV2;
 
 use crate::constants::*;
 use crate::errors::GameErrorCode;
+use crate::instructions::create_mint::create_metadata;
+use crate::state::game_config::GameConfig;
 use crate::state::player_data::PlayerData;
-use crate::state::plugin::Plugin;
+use crate::state::plugin::{Plugin, PluginStatus};
+use crate::state::plugin_registry::PluginRegistry;
 
-/// Create a new plugin that other players can install
+/// Create a new plugin that other players can install.
+/// Passing the NFT accounts also mints a 1-of-1 NFT to the creator; whoever
+/// holds it receives the plugin's install revenue.
 pub fn create_plugin(
     ctx: Context<CreatePlugin>,
     tier: u8,
     metadata_uri: String,
     creator_share_bps: u16,
 ) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_PLUGIN_CREATION)?;
+
     // Validate tier range
-    require!(tier >= 1 && tier <= 10, GameErrorCode::InvalidTier);
+    require!((1..=10).contains(&tier), GameErrorCode::InvalidTier);
 
     // Validate metadata URI length
     require!(metadata_uri.len() <= 200, GameErrorCode::MetadataUriTooLong);
@@ -27,7 +41,7 @@ pub fn create_plugin(
     );
 
     // Calculate creation cost
-    let creation_cost = calculate_creation_cost(tier);
+    let creation_cost = calculate_creation_cost(&ctx.accounts.game_config, tier)?;
 
     // Burn cookies from creator
     let burn_ctx = CpiContext::new(
@@ -40,28 +54,146 @@ pub fn create_plugin(
     );
     burn(burn_ctx, creation_cost)?;
 
-    // Increment global plugin counter
-    let plugin_id = ctx.accounts.player_data.plugin_global_counter;
-    ctx.accounts.player_data.plugin_global_counter =
-        plugin_id.checked_add(1).ok_or(GameErrorCode::Overflow)?;
+    // Take the next ID from the global registry
+    let registry = &mut ctx.accounts.plugin_registry;
+    let plugin_id = registry.next_plugin_id;
+    registry.next_plugin_id = plugin_id.checked_add(1).ok_or(GameErrorCode::Overflow)?;
+    registry.total_plugins = registry.total_plugins.saturating_add(1);
+
+    // Count the plugins this player created (see close_player)
+    ctx.accounts.player_data.plugin_global_counter = ctx
+        .accounts
+        .player_data
+        .plugin_global_counter
+        .checked_add(1)
+        .ok_or(GameErrorCode::Overflow)?;
 
     // Initialize plugin account
     let plugin = &mut ctx.accounts.plugin;
+    plugin.version = Plugin::VERSION;
     plugin.plugin_id = plugin_id;
     plugin.creator = ctx.accounts.creator.key();
     plugin.tier = tier;
     plugin.metadata_uri = metadata_uri;
     plugin.total_installs = 0;
+    plugin.active_installs = 0;
     plugin.creator_earnings = 0;
     plugin.created_at = Clock::get()?.unix_timestamp;
     plugin.creator_share_bps = creator_share_bps;
     plugin.burn_share_bps = burn_share_bps;
     plugin.bump = ctx.bumps.plugin;
+    plugin.pending_creator_share_bps = 0;
+    plugin.share_change_at = 0;
+    plugin.status = PluginStatus::Active;
+    plugin.rating_sum = 0;
+    plugin.rating_count = 0;
+    plugin.nft_mint = None;
+
+    if ctx.accounts.plugin_nft_mint.is_some() {
+        mint_plugin_nft(&ctx, plugin_id)?;
+        ctx.accounts.plugin.nft_mint = ctx.accounts.plugin_nft_mint.as_ref().map(|m| m.key());
+        msg!("Plugin NFT minted");
+    }
 
     msg!("Plugin created! ID: {}, Tier: {}", plugin_id, tier);
     Ok(())
 }
 
+/// Mint the plugin's NFT to the creator and lock its supply at one with a
+/// master edition
+fn mint_plugin_nft(ctx: &Context<CreatePlugin>, plugin_id: u64) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let (
+        Some(nft_mint),
+        Some(creator_nft_account),
+        Some(nft_metadata),
+        Some(nft_master_edition),
+        Some(_token_metadata_program),
+        Some(rent),
+    ) = (
+        &accounts.plugin_nft_mint,
+        &accounts.creator_nft_account,
+        &accounts.nft_metadata,
+        &accounts.nft_master_edition,
+        &accounts.token_metadata_program,
+        &accounts.rent,
+    )
+    else {
+        return err!(GameErrorCode::MissingNftAccount);
+    };
+
+    // The mint PDA is its own mint and update authority
+    let id_bytes = plugin_id.to_le_bytes();
+    let bump = ctx
+        .bumps
+        .plugin_nft_mint
+        .ok_or(GameErrorCode::MissingNftAccount)?;
+    let signer: &[&[&[u8]]] = &[&[b"plugin_nft", id_bytes.as_ref(), &[bump]]];
+
+    let mint_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        MintTo {
+            mint: nft_mint.to_account_info(),
+            to: creator_nft_account.to_account_info(),
+            authority: nft_mint.to_account_info(),
+        },
+        signer,
+    );
+    mint_to(mint_ctx, 1)?;
+
+    let data_v2 = DataV2 {
+        name: format!("Cookie Plugin #{}", plugin_id),
+        symbol: "PLUGIN".to_string(),
+        uri: accounts.plugin.metadata_uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    create_metadata(
+        nft_metadata,
+        &nft_mint.to_account_info(),
+        &accounts.creator,
+        &accounts.system_program,
+        &rent.to_account_info(),
+        data_v2,
+        signer,
+    )?;
+
+    // Moves the mint authority to the edition so no second token can exist
+    let create_master_edition_ix = CreateMasterEditionV3 {
+        edition: nft_master_edition.key(),
+        mint: nft_mint.key(),
+        update_authority: nft_mint.key(),
+        mint_authority: nft_mint.key(),
+        payer: accounts.creator.key(),
+        metadata: nft_metadata.key(),
+        token_program: accounts.token_program.key(),
+        system_program: accounts.system_program.key(),
+        rent: Some(rent.key()),
+    };
+    anchor_lang::solana_program::program::invoke_signed(
+        &create_master_edition_ix.instruction(CreateMasterEditionV3InstructionArgs {
+            max_supply: Some(0),
+        }),
+        &[
+            nft_master_edition.to_account_info(),
+            nft_mint.to_account_info(),
+            nft_mint.to_account_info(), // update authority
+            nft_mint.to_account_info(), // mint authority
+            accounts.creator.to_account_info(),
+            nft_metadata.to_account_info(),
+            accounts.token_program.to_account_info(),
+            accounts.system_program.to_account_info(),
+            rent.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(tier: u8, metadata_uri: String)]
 pub struct CreatePlugin<'info> {
@@ -75,14 +207,27 @@ pub struct CreatePlugin<'info> {
     )]
     pub player_data: Account<'info, PlayerData>,
 
-    /// Plugin account - PDA seeded by plugin_id
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"plugin_registry"],
+        bump = plugin_registry.bump,
+    )]
+    pub plugin_registry: Account<'info, PluginRegistry>,
+
+    /// Plugin account - PDA seeded by the registry's next plugin_id
     #[account(
         init,
         payer = creator,
         space = Plugin::LEN,
         seeds = [
             b"plugin",
-            player_data.plugin_global_counter.to_le_bytes().as_ref(),
+            plugin_registry.next_plugin_id.to_le_bytes().as_ref(),
         ],
         bump,
     )]
@@ -103,6 +248,44 @@ pub struct CreatePlugin<'info> {
     )]
     pub cookie_mint: Account<'info, Mint>,
 
+    /// Mint of the plugin's NFT, only passed to mint one
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            b"plugin_nft",
+            plugin_registry.next_plugin_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+        mint::decimals = 0,
+        mint::authority = plugin_nft_mint,
+        mint::freeze_authority = plugin_nft_mint,
+    )]
+    pub plugin_nft_mint: Option<Account<'info, Mint>>,
+
+    /// Creator's account for the plugin NFT
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = plugin_nft_mint,
+        associated_token::authority = creator,
+    )]
+    pub creator_nft_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Derivation is checked by the token metadata program
+    #[account(mut)]
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Derivation is checked by the token metadata program
+    #[account(mut)]
+    pub nft_master_edition: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
+    pub rent: Option<Sysvar<'info, Rent>>,
 }
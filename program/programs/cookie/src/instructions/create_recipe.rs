@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::program_admin::ProgramAdmin;
+use crate::state::recipe::{Recipe, RecipeOutput};
+
+/// Register a new crafting recipe (admin only)
+pub fn create_recipe(
+    ctx: Context<CreateRecipe>,
+    recipe_id: u64,
+    wood_cost: u64,
+    cookie_cost: u64,
+    output: RecipeOutput,
+) -> Result<()> {
+    output.validate()?;
+    // A recipe has to cost something, otherwise it's free energy or boosts
+    require!(
+        wood_cost > 0 || cookie_cost > 0,
+        GameErrorCode::InvalidRecipe
+    );
+
+    let recipe = &mut ctx.accounts.recipe;
+    recipe.recipe_id = recipe_id;
+    recipe.wood_cost = wood_cost;
+    recipe.cookie_cost = cookie_cost;
+    recipe.output = output;
+    recipe.enabled = true;
+    recipe.times_crafted = 0;
+    recipe.bump = ctx.bumps.recipe;
+
+    msg!("Recipe {} created", recipe_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(recipe_id: u64)]
+pub struct CreateRecipe<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Recipe::LEN,
+        seeds = [b"recipe", recipe_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub recipe: Account<'info, Recipe>,
+
+    pub system_program: Program<'info, System>,
+}
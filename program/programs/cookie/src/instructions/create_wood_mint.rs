@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use mpl_token_metadata::types::DataV2;
+
+use crate::errors::GameErrorCode;
+use crate::instructions::create_mint::create_metadata;
+use crate::state::program_admin::ProgramAdmin;
+
+/// Create the wood token mint and its metadata. Works like `create_mint`, but
+/// the mint PDA is seeded by "wood".
+pub fn create_wood_mint(
+    ctx: Context<CreateWoodMint>,
+    uri: String,
+    name: String,
+    symbol: String,
+) -> Result<()> {
+    // PDA seeds and bump to "sign" for CPI
+    let seeds = b"wood";
+    let bump = ctx.bumps.wood_mint;
+    let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
+
+    // On-chain token metadata for the mint
+    let data_v2 = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    create_metadata(
+        &ctx.accounts.metadata_account,
+        &ctx.accounts.wood_mint.to_account_info(),
+        &ctx.accounts.admin,
+        &ctx.accounts.system_program,
+        &ctx.accounts.rent.to_account_info(),
+        data_v2,
+        signer,
+    )
+}
+
+#[derive(Accounts)]
+pub struct CreateWoodMint<'info> {
+    /// The admin account that can create the mint
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    // The PDA is both the address of the mint account and the mint authority
+    #[account(
+        init,
+        seeds = [b"wood"],
+        bump,
+        payer = admin,
+        mint::decimals = 0, // Whole logs only 🪵
+        mint::authority = wood_mint,
+
+    )]
+    pub wood_mint: Account<'info, Mint>,
+
+    ///CHECK: Using "seeds" constraint to validate metadata account address
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            wood_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::program::Cookie;
+use crate::state::program_admin::ProgramAdmin;
+
+/// Create the program admin role. Only the program's upgrade authority can do
+/// this, so it can't be front-run right after deployment.
+pub fn init_admin(ctx: Context<InitAdmin>) -> Result<()> {
+    let program_admin = &mut ctx.accounts.program_admin;
+    program_admin.authority = ctx.accounts.signer.key();
+    program_admin.pending_authority = None;
+    program_admin.bump = ctx.bumps.program_admin;
+
+    msg!("Program admin set to {}", program_admin.authority);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitAdmin<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = ProgramAdmin::LEN,
+        seeds = [b"admin"],
+        bump,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Cookie>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(signer.key())
+            @ GameErrorCode::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
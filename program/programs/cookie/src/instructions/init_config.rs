@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+use crate::state::program_admin::ProgramAdmin;
+
+/// Create the global game config with the default economy values
+pub fn init_config(ctx: Context<InitConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.game_config;
+    config.version = GameConfig::VERSION;
+    config.set_defaults();
+    config.bump = ctx.bumps.game_config;
+    config.validate()?;
+
+    msg!("Game config initialized");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    /// Singleton config account
+    #[account(
+        init,
+        payer = admin,
+        space = GameConfig::LEN,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,16 +1,25 @@
 pub use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
 use crate::state::player_data::PlayerData;
-use crate::{constants::MAX_ENERGY, GameData};
+use crate::GameData;
 use anchor_lang::prelude::*;
 
 pub fn init_player(ctx: Context<InitPlayer>) -> Result<()> {
-    ctx.accounts.player.energy = MAX_ENERGY;
+    ctx.accounts.player.version = PlayerData::VERSION;
+    ctx.accounts.player.level = 1;
+    ctx.accounts.player.energy = ctx.accounts.game_config.max_energy_for_level(1);
     ctx.accounts.player.last_login = Clock::get()?.unix_timestamp;
     ctx.accounts.player.authority = ctx.accounts.signer.key();
 
     // Initialize plugin system fields
     ctx.accounts.player.unlocked_tiers = 1; // Tier 1 unlocked by default (bit 0 = 1)
     ctx.accounts.player.plugin_global_counter = 1; // Start at 1 (0 means "no plugin")
+    ctx.accounts.player.click_power = 1; // No plugins installed yet
+
+    let game_data_len = ctx.accounts.game_data.to_account_info().data_len();
+    ctx.accounts
+        .game_data
+        .init_or_require_current(game_data_len)?;
 
     Ok(())
 }
@@ -21,7 +30,7 @@ pub struct InitPlayer<'info> {
     #[account(
         init,
         payer = signer,
-        space = 1000, // 8+32+x+1+8+8+8 But taking 1000 to have space to expand easily.
+        space = PlayerData::LEN,
         seeds = [b"player".as_ref(), signer.key().as_ref()],
         bump,
     )]
@@ -30,12 +39,18 @@ pub struct InitPlayer<'info> {
     #[account(
         init_if_needed,
         payer = signer,
-        space = 1000, // 8 + 8 for anchor account discriminator and the u64. Using 1000 to have space to expand easily.
+        space = GameData::LEN,
         seeds = [level_seed.as_ref()],
         bump,
     )]
     pub game_data: Account<'info, GameData>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -0,0 +1,68 @@
+use std::collections::BTreeSet;
+
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::plugin::Plugin;
+use crate::state::plugin_registry::PluginRegistry;
+use crate::state::program_admin::ProgramAdmin;
+
+/// Create the global plugin registry (admin only).
+///
+/// Plugins created before the registry used per-player IDs, so their PDAs
+/// stay where they are and the registry starts counting after them. Pass every
+/// existing plugin once as a remaining account; `next_plugin_id` has to be
+/// above all of their IDs. Plugins with an old layout have to go through
+/// `migrate_account` first, which the admin can do for any plugin.
+pub fn init_plugin_registry<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InitPluginRegistry<'info>>,
+    next_plugin_id: u64,
+) -> Result<()> {
+    require!(next_plugin_id >= 1, GameErrorCode::InvalidConfig);
+
+    let mut existing = BTreeSet::new();
+    for info in ctx.remaining_accounts.iter() {
+        let plugin = Account::<Plugin>::try_from(info)?;
+        require!(
+            plugin.version == Plugin::VERSION,
+            GameErrorCode::AccountNotMigrated
+        );
+        require!(
+            plugin.plugin_id < next_plugin_id,
+            GameErrorCode::InvalidConfig
+        );
+        require!(existing.insert(info.key()), GameErrorCode::InvalidAccount);
+    }
+
+    let registry = &mut ctx.accounts.plugin_registry;
+    registry.next_plugin_id = next_plugin_id;
+    registry.total_plugins = existing.len() as u64;
+    registry.bump = ctx.bumps.plugin_registry;
+
+    msg!("Plugin registry created, next plugin ID: {}", next_plugin_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitPluginRegistry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = PluginRegistry::LEN,
+        seeds = [b"plugin_registry"],
+        bump,
+    )]
+    pub plugin_registry: Account<'info, PluginRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
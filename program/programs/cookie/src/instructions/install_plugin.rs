@@ -3,12 +3,29 @@ use anchor_spl::token::{burn, mint_to, Burn, Mint, MintTo, Token, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::GameErrorCode;
-use crate::state::plugin::Plugin;
+use crate::state::game_config::GameConfig;
+use crate::state::plugin::{Plugin, PluginStatus};
 use crate::state::player_data::PlayerData;
 use crate::state::player_plugin_slot::PlayerPluginSlot;
+use crate::state::plugin_rating::PluginRating;
 
 /// Install a plugin into a tier slot
 pub fn install_plugin(ctx: Context<InstallPlugin>) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_PLUGIN_ECONOMY)?;
+
+    // Only listed plugins can be installed
+    require!(
+        ctx.accounts.plugin.status == PluginStatus::Active,
+        GameErrorCode::PluginNotActive
+    );
+
+    // A scheduled share change applies from the first install after it's due
+    ctx.accounts
+        .plugin
+        .apply_pending_share(Clock::get()?.unix_timestamp);
+
     // Extract values we need before mutating
     let plugin_id = ctx.accounts.plugin.plugin_id;
     let tier = ctx.accounts.plugin.tier;
@@ -23,37 +40,73 @@ pub fn install_plugin(ctx: Context<InstallPlugin>) -> Result<()> {
         GameErrorCode::TierNotUnlocked
     );
     
-    // If slot has an existing plugin, claim any pending cookies first
-    if slot.plugin_id != 0 {
-        let accumulated = calculate_accumulated_cookies(
-            tier,
-            slot.last_claim,
-            Clock::get()?.unix_timestamp,
+    // Reinstalling the same plugin doesn't change its active install count
+    let is_reinstall = slot.plugin_id == plugin_id;
+
+    // Settle the plugin already in the slot first. A replaced plugin also
+    // loses an active install.
+    let current_time = Clock::get()?.unix_timestamp;
+    let accumulated = if is_reinstall {
+        slot.pending_cookies(
+            &ctx.accounts.plugin,
+            &ctx.accounts.game_config,
+            &ctx.accounts.player_data,
+            current_time,
+        )
+    } else if slot.plugin_id != 0 {
+        let previous_plugin = ctx
+            .accounts
+            .previous_plugin
+            .as_mut()
+            .ok_or(GameErrorCode::MissingPluginAccount)?;
+        require!(
+            previous_plugin.plugin_id == slot.plugin_id,
+            GameErrorCode::MissingPluginAccount
+        );
+        previous_plugin.active_installs = previous_plugin.active_installs.saturating_sub(1);
+        slot.pending_cookies(
+            previous_plugin,
+            &ctx.accounts.game_config,
+            &ctx.accounts.player_data,
+            current_time,
+        )
+    } else {
+        0
+    };
+
+    if accumulated > 0 {
+        // Mint accumulated cookies to player
+        let seeds = b"reward";
+        let bump = ctx.bumps.cookie_mint;
+        let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
+        
+        let mint_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.cookie_mint.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: ctx.accounts.cookie_mint.to_account_info(),
+            },
+            signer,
         );
+        mint_to(mint_ctx, accumulated)?;
         
-        if accumulated > 0 {
-            // Mint accumulated cookies to player
-            let seeds = b"reward";
-            let bump = ctx.bumps.cookie_mint;
-            let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
-            
-            let mint_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.cookie_mint.to_account_info(),
-                    to: ctx.accounts.player_token_account.to_account_info(),
-                    authority: ctx.accounts.cookie_mint.to_account_info(),
-                },
-                signer,
-            );
-            mint_to(mint_ctx, accumulated)?;
-            
-            msg!("Claimed {} cookies before replacing plugin", accumulated);
-        }
+        msg!("Claimed {} cookies before replacing plugin", accumulated);
     }
+
+    // Revenue goes to the NFT holder if the plugin has one, otherwise to the creator
+    let revenue_recipient = ctx
+        .accounts
+        .plugin
+        .rights_holder(ctx.accounts.nft_holder_account.as_deref())?;
+    require_keys_eq!(
+        ctx.accounts.creator_token_account.owner,
+        revenue_recipient,
+        GameErrorCode::WrongRevenueRecipient
+    );
     
     // Calculate install cost
-    let install_cost = calculate_install_cost(tier);
+    let install_cost = calculate_install_cost(&ctx.accounts.game_config, tier);
     
     // Split install cost: creator share + burn share
     let creator_amount = (install_cost as u128 * creator_share_bps as u128 / 10000) as u64;
@@ -72,7 +125,7 @@ pub fn install_plugin(ctx: Context<InstallPlugin>) -> Result<()> {
         burn(burn_ctx, burn_amount)?;
     }
     
-    // Send creator_share to creator
+    // Send creator_share to the revenue recipient
     if creator_amount > 0 {
         let seeds = b"reward";
         let bump = ctx.bumps.cookie_mint;
@@ -101,9 +154,15 @@ pub fn install_plugin(ctx: Context<InstallPlugin>) -> Result<()> {
         .total_installs
         .checked_add(1)
         .ok_or(GameErrorCode::Overflow)?;
+    if !is_reinstall {
+        ctx.accounts.plugin.active_installs = ctx.accounts.plugin
+            .active_installs
+            .checked_add(1)
+            .ok_or(GameErrorCode::Overflow)?;
+    }
     
     // Update slot
-    let current_time = Clock::get()?.unix_timestamp;
+    slot.version = PlayerPluginSlot::VERSION;
     slot.player = ctx.accounts.player.key();
     slot.tier = tier;
     slot.plugin_id = plugin_id;
@@ -111,6 +170,25 @@ pub fn install_plugin(ctx: Context<InstallPlugin>) -> Result<()> {
     slot.last_claim = current_time;
     slot.total_claimed = 0;
     slot.bump = ctx.bumps.player_plugin_slot;
+
+    // The first install of a plugin is what lets the player rate it
+    let plugin_rating = &mut ctx.accounts.plugin_rating;
+    if plugin_rating.player == Pubkey::default() {
+        plugin_rating.plugin = ctx.accounts.plugin.key();
+        plugin_rating.player = ctx.accounts.player.key();
+        plugin_rating.bump = ctx.bumps.plugin_rating;
+        ctx.accounts.player_data.used_plugins = ctx
+            .accounts
+            .player_data
+            .used_plugins
+            .checked_add(1)
+            .ok_or(GameErrorCode::Overflow)?;
+    }
+
+    // Occupied tiers boost clicking
+    ctx.accounts
+        .player_data
+        .set_tier_installed(&ctx.accounts.game_config, tier, true);
     
     msg!(
         "Plugin {} installed in tier {} slot. Cost: {} (burned: {}, creator: {})",
@@ -129,11 +207,18 @@ pub struct InstallPlugin<'info> {
     pub player: Signer<'info>,
     
     #[account(
+        mut,
         seeds = [b"player", player.key().as_ref()],
         bump,
     )]
     pub player_data: Account<'info, PlayerData>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
     /// Plugin to install
     #[account(
         mut,
@@ -141,6 +226,14 @@ pub struct InstallPlugin<'info> {
         bump = plugin.bump,
     )]
     pub plugin: Account<'info, Plugin>,
+
+    /// Plugin currently in the slot, required when replacing a different plugin
+    #[account(
+        mut,
+        seeds = [b"plugin", previous_plugin.plugin_id.to_le_bytes().as_ref()],
+        bump = previous_plugin.bump,
+    )]
+    pub previous_plugin: Option<Account<'info, Plugin>>,
     
     /// Player's plugin slot for this tier
     #[account(
@@ -155,6 +248,16 @@ pub struct InstallPlugin<'info> {
         bump,
     )]
     pub player_plugin_slot: Account<'info, PlayerPluginSlot>,
+
+    /// Player's rating of the plugin, created unrated on first install
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PluginRating::LEN,
+        seeds = [b"plugin_rating", plugin.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub plugin_rating: Account<'info, PluginRating>,
     
     /// Player's cookie token account (to pay install cost)
     #[account(
@@ -164,13 +267,16 @@ pub struct InstallPlugin<'info> {
     )]
     pub player_token_account: Account<'info, TokenAccount>,
     
-    /// Creator's cookie token account (to receive revenue share)
+    /// Cookie token account of the revenue recipient: the plugin NFT holder,
+    /// or the creator if the plugin has no NFT
     #[account(
         mut,
-        associated_token::mint = cookie_mint,
-        associated_token::authority = plugin.creator,
+        token::mint = cookie_mint,
     )]
     pub creator_token_account: Account<'info, TokenAccount>,
+
+    /// Token account holding the plugin NFT, required if the plugin has one
+    pub nft_holder_account: Option<Account<'info, TokenAccount>>,
     
     #[account(
         mut,
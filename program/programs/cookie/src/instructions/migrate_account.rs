@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+use crate::state::game_data::GameData;
+use crate::state::legacy::*;
+use crate::state::player_data::PlayerData;
+use crate::state::player_plugin_slot::PlayerPluginSlot;
+use crate::state::plugin::Plugin;
+use crate::state::plugin_rating::PluginRating;
+use crate::state::program_admin::ProgramAdmin;
+
+/// Upgrade a PlayerData, GameData, Plugin or PlayerPluginSlot account to the
+/// current layout and resize it to exactly `8 + INIT_SPACE`.
+/// Surplus rent goes back to the authority; if the account grows the authority
+/// pays the difference.
+///
+/// Authority is the player for PlayerData and slots, the creator for plugins
+/// and the program admin for GameData. The admin can also migrate plugins by
+/// passing `program_admin`, so an inactive creator doesn't hold them back.
+///
+/// Plugins didn't count their installs before and players didn't track their
+/// occupied tiers or ratings, so migrating a slot with a plugin installed
+/// counts it on that plugin, marks the tier on the player and creates the
+/// player's rating of the plugin if missing. The plugin and player have to be
+/// migrated first.
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    require_keys_eq!(*account.owner, crate::ID, GameErrorCode::InvalidAccount);
+
+    // Plugin and player passed alongside are written back on exit, so they
+    // must already be on the current layout
+    if let Some(plugin) = &ctx.accounts.plugin {
+        require!(
+            plugin.to_account_info().data_len() == Plugin::LEN
+                && plugin.version == Plugin::VERSION,
+            GameErrorCode::AccountNotMigrated
+        );
+    }
+    if let Some(player_data) = &ctx.accounts.player_data {
+        require!(
+            player_data.to_account_info().data_len() == PlayerData::LEN
+                && player_data.version == PlayerData::VERSION,
+            GameErrorCode::AccountNotMigrated
+        );
+    }
+
+    let (expected_authority, new_data, new_len) = {
+        let data = account.try_borrow_data()?;
+        require!(data.len() >= 8, GameErrorCode::InvalidAccount);
+        let discriminator: [u8; 8] = data[..8].try_into().unwrap();
+
+        if discriminator == PlayerData::DISCRIMINATOR {
+            require!(data.len() == PlayerDataV0::LEN, GameErrorCode::AlreadyMigrated);
+            let player: PlayerData = PlayerDataV0::deserialize(&mut &data[8..])?.into();
+            (player.authority, serialize(&player)?, PlayerData::LEN)
+        } else if discriminator == GameData::DISCRIMINATOR {
+            require!(data.len() == GameDataV0::LEN, GameErrorCode::AlreadyMigrated);
+            let game_data: GameData = GameDataV0::deserialize(&mut &data[8..])?.into();
+            let admin = ctx
+                .accounts
+                .program_admin
+                .as_ref()
+                .ok_or(GameErrorCode::Unauthorized)?;
+            (admin.authority, serialize(&game_data)?, GameData::LEN)
+        } else if discriminator == Plugin::DISCRIMINATOR {
+            require!(data.len() == PluginV0::LEN, GameErrorCode::AlreadyMigrated);
+            let plugin: Plugin = PluginV0::deserialize(&mut &data[8..])?.into();
+            let authority = match &ctx.accounts.program_admin {
+                Some(admin) => admin.authority,
+                None => plugin.creator,
+            };
+            (authority, serialize(&plugin)?, Plugin::LEN)
+        } else if discriminator == PlayerPluginSlot::DISCRIMINATOR {
+            require!(
+                data.len() == PlayerPluginSlotV0::LEN,
+                GameErrorCode::AlreadyMigrated
+            );
+            let slot: PlayerPluginSlot = PlayerPluginSlotV0::deserialize(&mut &data[8..])?.into();
+            if slot.plugin_id != 0 {
+                let plugin = ctx
+                    .accounts
+                    .plugin
+                    .as_mut()
+                    .ok_or(GameErrorCode::MissingPluginAccount)?;
+                require!(
+                    plugin.plugin_id == slot.plugin_id,
+                    GameErrorCode::MissingPluginAccount
+                );
+                plugin.active_installs = plugin
+                    .active_installs
+                    .checked_add(1)
+                    .ok_or(GameErrorCode::Overflow)?;
+
+                let player_data = ctx
+                    .accounts
+                    .player_data
+                    .as_mut()
+                    .ok_or(GameErrorCode::AccountNotMigrated)?;
+                let game_config = ctx
+                    .accounts
+                    .game_config
+                    .as_ref()
+                    .ok_or(GameErrorCode::InvalidConfig)?;
+                player_data.set_tier_installed(game_config, slot.tier, true);
+
+                // Lets the player rate the plugin they already use
+                let plugin_rating = ctx
+                    .accounts
+                    .plugin_rating
+                    .as_mut()
+                    .ok_or(GameErrorCode::MissingRatingAccount)?;
+                if plugin_rating.player == Pubkey::default() {
+                    plugin_rating.plugin = plugin.key();
+                    plugin_rating.player = slot.player;
+                    plugin_rating.bump = ctx
+                        .bumps
+                        .plugin_rating
+                        .ok_or(GameErrorCode::MissingRatingAccount)?;
+                    player_data.used_plugins = player_data
+                        .used_plugins
+                        .checked_add(1)
+                        .ok_or(GameErrorCode::Overflow)?;
+                }
+            }
+            (slot.player, serialize(&slot)?, PlayerPluginSlot::LEN)
+        } else {
+            return err!(GameErrorCode::InvalidAccount);
+        }
+    };
+
+    require_keys_eq!(
+        expected_authority,
+        ctx.accounts.authority.key(),
+        GameErrorCode::WrongAuthority
+    );
+
+    // Resize and write the new layout
+    let old_len = account.data_len();
+    account.realloc(new_len, true)?;
+    account.try_borrow_mut_data()?[..new_data.len()].copy_from_slice(&new_data);
+
+    // Settle rent for the new size
+    let required = Rent::get()?.minimum_balance(new_len);
+    let current = account.lamports();
+    if current > required {
+        let surplus = current - required;
+        **account.try_borrow_mut_lamports()? -= surplus;
+        **ctx.accounts.authority.try_borrow_mut_lamports()? += surplus;
+        msg!("Refunded {} lamports", surplus);
+    } else if current < required {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: account.clone(),
+            },
+        );
+        transfer(transfer_ctx, required - current)?;
+    }
+
+    msg!("Account migrated ({} -> {} bytes)", old_len, new_len);
+    Ok(())
+}
+
+fn serialize<T: AccountSerialize>(account: &T) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data)?;
+    Ok(data)
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Owner and discriminator are checked in the handler
+    #[account(mut)]
+    pub account: UncheckedAccount<'info>,
+
+    /// Only needed to migrate GameData, or a plugin on the creator's behalf
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+    )]
+    pub program_admin: Option<Account<'info, ProgramAdmin>>,
+
+    /// Only needed to migrate a slot with a plugin installed: that plugin
+    #[account(
+        mut,
+        seeds = [b"plugin", plugin.plugin_id.to_le_bytes().as_ref()],
+        bump = plugin.bump,
+    )]
+    pub plugin: Option<Account<'info, Plugin>>,
+
+    /// Only needed to migrate a slot with a plugin installed: the player's data
+    #[account(
+        mut,
+        seeds = [b"player", authority.key().as_ref()],
+        bump,
+    )]
+    pub player_data: Option<Account<'info, PlayerData>>,
+
+    /// Only needed to migrate a slot with a plugin installed
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Option<Account<'info, GameConfig>>,
+
+    /// Only needed to migrate a slot with a plugin installed: the player's
+    /// rating of that plugin, created unrated if missing
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PluginRating::LEN,
+        seeds = [
+            b"plugin_rating",
+            plugin.as_ref().map(|plugin| plugin.key()).unwrap_or_default().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub plugin_rating: Option<Account<'info, PluginRating>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+
+use crate::errors::GameErrorCode;
+use crate::state::player_data::PlayerData;
+
+/// Convert the `wood` counter from before wood was a token into wood tokens.
+/// Anyone can pay for the conversion, the tokens always go to the player's
+/// own token account.
+pub fn migrate_wood(ctx: Context<MigrateWood>) -> Result<()> {
+    let wood = ctx.accounts.player.wood;
+    require!(wood > 0, GameErrorCode::NoWoodToMigrate);
+    ctx.accounts.player.wood = 0;
+
+    // PDA seeds and bump to "sign" for CPI
+    let seeds = b"wood";
+    let bump = ctx.bumps.wood_mint;
+    let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.wood_mint.to_account_info(),
+            to: ctx.accounts.player_wood_account.to_account_info(),
+            authority: ctx.accounts.wood_mint.to_account_info(),
+        },
+        signer,
+    );
+    mint_to(cpi_ctx, wood)?;
+
+    msg!("Converted {} wood into tokens", wood);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateWood<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player".as_ref(), player.authority.key().as_ref()],
+        bump,
+    )]
+    pub player: Account<'info, PlayerData>,
+
+    /// CHECK: The player's main wallet that owns the wood token account
+    #[account(address = player.authority)]
+    pub player_authority: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = wood_mint,
+        associated_token::authority = player_authority
+    )]
+    pub player_wood_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"wood"],
+        bump,
+    )]
+    pub wood_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,20 +1,68 @@
 //! All instructions
+pub mod accept_admin;
 pub mod chop_tree;
+pub mod claim_daily_reward;
+pub mod claim_golden_cookie;
 pub mod claim_plugin_cookies;
+pub mod close_player;
 pub mod create_mint;
+pub mod craft;
 pub mod create_plugin;
+pub mod create_recipe;
+pub mod create_wood_mint;
+pub mod init_admin;
+pub mod init_config;
 pub mod init_player;
+pub mod init_plugin_registry;
 pub mod install_plugin;
+pub mod migrate_account;
+pub mod migrate_wood;
+pub mod moderate_plugin;
 pub mod on_click;
+pub mod on_click_batch;
+pub mod propose_admin;
+pub mod rate_plugin;
+pub mod set_paused;
+pub mod set_plugin_listing;
+pub mod set_player_name;
 pub mod uninstall_plugin;
 pub mod unlock_tier;
+pub mod update_config;
+pub mod update_mint_metadata;
+pub mod update_plugin;
+pub mod update_recipe;
+pub mod upgrade_axe;
 
+pub use accept_admin::*;
 pub use chop_tree::*;
+pub use claim_daily_reward::*;
+pub use claim_golden_cookie::*;
 pub use claim_plugin_cookies::*;
+pub use close_player::*;
 pub use create_mint::*;
+pub use craft::*;
 pub use create_plugin::*;
+pub use create_recipe::*;
+pub use create_wood_mint::*;
+pub use init_admin::*;
+pub use init_config::*;
 pub use init_player::*;
+pub use init_plugin_registry::*;
 pub use install_plugin::*;
+pub use migrate_account::*;
+pub use migrate_wood::*;
+pub use moderate_plugin::*;
 pub use on_click::*;
+pub use on_click_batch::*;
+pub use propose_admin::*;
+pub use rate_plugin::*;
+pub use set_paused::*;
+pub use set_plugin_listing::*;
+pub use set_player_name::*;
 pub use uninstall_plugin::*;
 pub use unlock_tier::*;
+pub use update_config::*;
+pub use update_mint_metadata::*;
+pub use update_plugin::*;
+pub use update_recipe::*;
+pub use upgrade_axe::*;
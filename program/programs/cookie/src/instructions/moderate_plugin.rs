@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::events::PluginStatusChanged;
+use crate::state::plugin::{Plugin, PluginStatus};
+use crate::state::program_admin::ProgramAdmin;
+
+/// Set any status on a plugin (admin only). Hiding pulls it from the shop;
+/// banning also stops every installed copy from producing.
+pub fn moderate_plugin(ctx: Context<ModeratePlugin>, status: PluginStatus) -> Result<()> {
+    let plugin = &mut ctx.accounts.plugin;
+    plugin.status = status;
+
+    emit!(PluginStatusChanged {
+        plugin_id: plugin.plugin_id,
+        status,
+        changed_by: ctx.accounts.admin.key(),
+    });
+
+    msg!("Plugin {} moderated", plugin.plugin_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ModeratePlugin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    #[account(
+        mut,
+        seeds = [b"plugin", plugin.plugin_id.to_le_bytes().as_ref()],
+        bump = plugin.bump,
+    )]
+    pub plugin: Account<'info, Plugin>,
+}
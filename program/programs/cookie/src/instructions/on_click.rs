@@ -1,17 +1,67 @@
 pub use crate::errors::GameErrorCode;
+use crate::constants::PAUSE_CLICKING;
+use crate::events::ClickEvent;
+use crate::state::game_config::GameConfig;
 use crate::state::player_data::PlayerData;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    self, load_current_index_checked, load_instruction_at_checked,
+};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 use session_keys::{Session, SessionToken};
 
-pub fn on_click(ctx: Context<OnClick>) -> Result<()> {
+pub fn on_click(ctx: Context<OnClick>, counter: u16) -> Result<()> {
+    click(ctx, 1, counter)
+}
+
+/// Shared by `on_click` and `on_click_batch`: spend energy for `count` clicks
+/// and mint `click_power` cookies per click, boosted by the combo, in a single
+/// CPI. A golden cookie frenzy multiplies the reward further. `counter` is the
+/// player's nonce, so a session-signed click can't be replayed.
+pub fn click(ctx: Context<OnClick>, count: u64, counter: u16) -> Result<()> {
+    ctx.accounts.game_config.require_not_paused(PAUSE_CLICKING)?;
+
+    // The top-level instruction must be ours, so another program can't wrap
+    // clicking in a CPI (e.g. an on-chain click farm)
+    let instructions_sysvar = ctx.accounts.instructions.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)?;
+    let current_ix =
+        load_instruction_at_checked(current_index as usize, &instructions_sysvar)?;
+    require_keys_eq!(
+        current_ix.program_id,
+        crate::ID,
+        GameErrorCode::CpiNotAllowed
+    );
+
+    ctx.accounts.player.use_nonce(counter)?;
+
+    let slot = Clock::get()?.slot;
+    ctx.accounts.player.check_click_rate(
+        &ctx.accounts.game_config,
+        count.min(u32::MAX as u64) as u32,
+        slot,
+    )?;
+
+    let energy_cost = ctx
+        .accounts
+        .game_config
+        .click_energy_cost
+        .checked_mul(count)
+        .ok_or(GameErrorCode::NotEnoughEnergy)?;
+    ctx.accounts.player.update_energy(&ctx.accounts.game_config)?;
+
     // Check if player has enough energy
-    if ctx.accounts.player.energy < 5 {
+    if ctx.accounts.player.energy < energy_cost {
         return err!(GameErrorCode::NotEnoughEnergy);
     }
-    // Subtract 5 energy from player
-    ctx.accounts.player.energy = ctx.accounts.player.energy.checked_sub(5).unwrap();
+    // Subtract the click cost from player energy
+    ctx.accounts.player.energy = ctx
+        .accounts
+        .player
+        .energy
+        .checked_sub(energy_cost)
+        .unwrap();
 
     // PDA seeds and bump to "sign" for CPI
     let seeds = b"reward";
@@ -29,10 +79,42 @@ pub fn on_click(ctx: Context<OnClick>) -> Result<()> {
         signer,
     );
 
-    // Mint 1 cookie per click
-    let amount = 1u64;
+    // Clicking fast keeps the combo going
+    let now = Clock::get()?.unix_timestamp;
+    let combo_bps = ctx.accounts.player.register_clicks(
+        &ctx.accounts.game_config,
+        count.min(u32::MAX as u64) as u32,
+        now,
+    );
+    // A golden cookie frenzy stacks on top of the combo
+    let frenzy_bps = ctx.accounts.player.frenzy_multiplier_at(now);
+    let multiplier_bps =
+        (combo_bps as u64 * frenzy_bps as u64 / 10000).min(u32::MAX as u64) as u32;
 
+    // Mint click_power cookies per click (accounts from before plugins boosted
+    // clicks have a click power of 0)
+    let base_amount = ctx
+        .accounts
+        .player
+        .click_power
+        .max(1)
+        .checked_mul(count)
+        .ok_or(GameErrorCode::Overflow)?;
+    let amount = (base_amount as u128 * multiplier_bps as u128 / 10000) as u64;
     mint_to(cpi_ctx, amount)?;
+
+    ctx.accounts.player.total_clicks = ctx.accounts.player.total_clicks.saturating_add(count);
+
+    let xp = ctx.accounts.game_config.xp_per_click.saturating_mul(count);
+    ctx.accounts.player.add_xp(&ctx.accounts.game_config, xp)?;
+
+    emit!(ClickEvent {
+        player: ctx.accounts.player.authority,
+        clicks: count,
+        cookies: amount,
+        combo: ctx.accounts.player.combo,
+        multiplier_bps,
+    });
     Ok(())
 }
 
@@ -54,6 +136,12 @@ pub struct OnClick<'info> {
     )]
     pub player: Account<'info, PlayerData>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
 
@@ -77,6 +165,10 @@ pub struct OnClick<'info> {
     )]
     pub reward_token_mint: Account<'info, Mint>,
 
+    /// CHECK: Instructions sysvar, used to reject calls made through CPI
+    #[account(address = instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -0,0 +1,16 @@
+use crate::errors::GameErrorCode;
+use crate::instructions::on_click::{click, OnClick};
+use anchor_lang::prelude::*;
+
+pub fn on_click_batch(ctx: Context<OnClick>, count: u16, counter: u16) -> Result<()> {
+    // A batch can't hold more clicks than the rate limit allows per window
+    require!(
+        count >= 1 && count as u32 <= ctx.accounts.game_config.max_clicks_per_window,
+        GameErrorCode::InvalidClickCount
+    );
+
+    click(ctx, count as u64, counter)?;
+
+    msg!("Clicked {} times", count);
+    Ok(())
+}
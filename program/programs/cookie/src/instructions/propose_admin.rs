@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::program_admin::ProgramAdmin;
+
+/// First step of an admin rotation. The new admin has to call `accept_admin`
+/// before anything changes, so a typo can't brick the program.
+/// Passing `None` cancels a pending proposal.
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_authority: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.program_admin.pending_authority = new_authority;
+
+    match new_authority {
+        Some(key) => msg!("Proposed new admin: {}", key),
+        None => msg!("Admin proposal cancelled"),
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::*;
+use crate::errors::GameErrorCode;
+use crate::events::PluginRated;
+use crate::state::game_config::GameConfig;
+use crate::state::plugin::Plugin;
+use crate::state::plugin_rating::PluginRating;
+
+/// Rate a plugin from 1 to 5, or change an earlier rating.
+/// Only players who installed the plugin at some point can rate it.
+pub fn rate_plugin(ctx: Context<RatePlugin>, rating: u8) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_PLUGIN_ECONOMY)?;
+
+    require!(
+        (PluginRating::MIN_RATING..=PluginRating::MAX_RATING).contains(&rating),
+        GameErrorCode::InvalidRating
+    );
+
+    let plugin = &mut ctx.accounts.plugin;
+    require!(
+        plugin.rights_holder(ctx.accounts.nft_holder_account.as_deref())?
+            != ctx.accounts.player.key(),
+        GameErrorCode::CannotRateOwnPlugin
+    );
+
+    let plugin_rating = &mut ctx.accounts.plugin_rating;
+    let previous = (plugin_rating.rating != 0).then_some(plugin_rating.rating);
+    plugin.record_rating(previous, rating);
+
+    plugin_rating.rating = rating;
+    plugin_rating.rated_at = Clock::get()?.unix_timestamp;
+
+    emit!(PluginRated {
+        plugin_id: plugin.plugin_id,
+        player: ctx.accounts.player.key(),
+        rating,
+        rating_sum: plugin.rating_sum,
+        rating_count: plugin.rating_count,
+    });
+
+    msg!("Plugin {} rated {}", plugin.plugin_id, rating);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RatePlugin<'info> {
+    pub player: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"plugin", plugin.plugin_id.to_le_bytes().as_ref()],
+        bump = plugin.bump,
+    )]
+    pub plugin: Account<'info, Plugin>,
+
+    /// Created by install_plugin, so only players who installed the plugin
+    /// have one
+    #[account(
+        mut,
+        seeds = [b"plugin_rating", plugin.key().as_ref(), player.key().as_ref()],
+        bump = plugin_rating.bump,
+        constraint = plugin_rating.player == player.key() @ GameErrorCode::PluginNotUsed,
+    )]
+    pub plugin_rating: Account<'info, PluginRating>,
+
+    /// Token account holding the plugin NFT, required if the plugin has one
+    pub nft_holder_account: Option<Account<'info, TokenAccount>>,
+}
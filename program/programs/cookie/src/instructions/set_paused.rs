@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PAUSE_ALL;
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+use crate::state::program_admin::ProgramAdmin;
+
+/// Pause or resume instruction families (admin only).
+/// `flags` replaces the whole bitmask, 0 resumes everything.
+pub fn set_paused(ctx: Context<SetPaused>, flags: u8) -> Result<()> {
+    require!(flags & !PAUSE_ALL == 0, GameErrorCode::InvalidConfig);
+
+    ctx.accounts.game_config.paused = flags;
+
+    msg!("Pause flags set to {:#07b}", flags);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::player_data::PlayerData;
+use crate::state::player_name::PlayerName;
+
+/// Set or change the player's display name.
+/// Claims the name registry PDA for the new name and releases the old one.
+pub fn set_player_name(ctx: Context<SetPlayerName>, name: String) -> Result<()> {
+    PlayerName::validate(&name)?;
+
+    let player_key = ctx.accounts.player.key();
+    let old_name = ctx.accounts.player_data.name.clone();
+    let same_entry = !old_name.is_empty() && old_name.to_lowercase() == name.to_lowercase();
+
+    // Claim the registry entry for the new name
+    let registry = &mut ctx.accounts.name_registry;
+    require!(
+        registry.owner == Pubkey::default() || registry.owner == player_key,
+        GameErrorCode::NameTaken
+    );
+    registry.owner = player_key;
+    registry.name = name.clone();
+    registry.bump = ctx.bumps.name_registry;
+
+    // Release the old name, unless only the casing changed
+    if !old_name.is_empty() && !same_entry {
+        let old_registry = ctx
+            .accounts
+            .old_name_registry
+            .as_ref()
+            .ok_or(GameErrorCode::OldNameRequired)?;
+        require_keys_eq!(
+            old_registry.owner,
+            player_key,
+            GameErrorCode::WrongAuthority
+        );
+        require!(
+            old_registry.name.to_lowercase() == old_name.to_lowercase(),
+            GameErrorCode::OldNameRequired
+        );
+        old_registry.close(ctx.accounts.player.to_account_info())?;
+    }
+
+    ctx.accounts.player_data.name = name;
+
+    msg!("Player name set to {}", ctx.accounts.player_data.name);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct SetPlayerName<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+    )]
+    pub player_data: Account<'info, PlayerData>,
+
+    /// Registry entry for the new name
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PlayerName::LEN,
+        seeds = [b"name", name.to_lowercase().as_bytes()],
+        bump,
+    )]
+    pub name_registry: Account<'info, PlayerName>,
+
+    /// Registry entry for the current name, required when renaming
+    #[account(mut)]
+    pub old_name_registry: Option<Account<'info, PlayerName>>,
+
+    pub system_program: Program<'info, System>,
+}
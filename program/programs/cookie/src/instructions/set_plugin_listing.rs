@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::GameErrorCode;
+use crate::events::PluginStatusChanged;
+use crate::state::plugin::{Plugin, PluginStatus};
+
+/// List or delist a plugin (creator, or the NFT holder if the plugin has one).
+/// Delisted plugins can't be installed, but existing installs keep producing.
+/// Plugins a moderator has hidden or banned can't be relisted by their owner.
+pub fn set_plugin_listing(ctx: Context<SetPluginListing>, listed: bool) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts
+            .plugin
+            .rights_holder(ctx.accounts.nft_holder_account.as_deref())?,
+        ctx.accounts.owner.key(),
+        GameErrorCode::Unauthorized
+    );
+
+    let plugin = &mut ctx.accounts.plugin;
+    require!(
+        matches!(plugin.status, PluginStatus::Active | PluginStatus::Delisted),
+        GameErrorCode::PluginModerated
+    );
+
+    plugin.status = if listed {
+        PluginStatus::Active
+    } else {
+        PluginStatus::Delisted
+    };
+
+    emit!(PluginStatusChanged {
+        plugin_id: plugin.plugin_id,
+        status: plugin.status,
+        changed_by: ctx.accounts.owner.key(),
+    });
+
+    msg!(
+        "Plugin {} {}",
+        plugin.plugin_id,
+        if listed { "listed" } else { "delisted" }
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPluginListing<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"plugin", plugin.plugin_id.to_le_bytes().as_ref()],
+        bump = plugin.bump,
+    )]
+    pub plugin: Account<'info, Plugin>,
+
+    /// Token account holding the plugin NFT, required if the plugin has one
+    pub nft_holder_account: Option<Account<'info, TokenAccount>>,
+}
@@ -3,18 +3,30 @@ use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+use crate::state::player_data::PlayerData;
+use crate::state::plugin::Plugin;
 use crate::state::player_plugin_slot::PlayerPluginSlot;
 
 /// Uninstall a plugin from a tier slot (claims pending cookies first)
 pub fn uninstall_plugin(ctx: Context<UninstallPlugin>, tier: u8) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_PLUGIN_ECONOMY)?;
+
     let slot = &mut ctx.accounts.player_plugin_slot;
     
     // Check if slot has a plugin installed
     require!(slot.plugin_id != 0, GameErrorCode::NoPluginInstalled);
     
-    // Claim any pending cookies first
+    // Claim any pending cookies first (nothing if the plugin has been banned)
     let current_time = Clock::get()?.unix_timestamp;
-    let accumulated = calculate_accumulated_cookies(tier, slot.last_claim, current_time);
+    let accumulated = slot.pending_cookies(
+        &ctx.accounts.plugin,
+        &ctx.accounts.game_config,
+        &ctx.accounts.player_data,
+        current_time,
+    );
     
     if accumulated > 0 {
         // Mint cookies to player
@@ -36,10 +48,19 @@ pub fn uninstall_plugin(ctx: Context<UninstallPlugin>, tier: u8) -> Result<()> {
         msg!("Claimed {} cookies before uninstalling", accumulated);
     }
     
+    // The plugin loses an active install
+    let plugin = &mut ctx.accounts.plugin;
+    plugin.active_installs = plugin.active_installs.saturating_sub(1);
+
     // Clear the slot (set plugin_id to 0)
     let old_plugin_id = slot.plugin_id;
     slot.plugin_id = 0;
     slot.last_claim = current_time;
+
+    // The empty tier no longer boosts clicking
+    ctx.accounts
+        .player_data
+        .set_tier_installed(&ctx.accounts.game_config, tier, false);
     
     msg!("Plugin {} uninstalled from tier {} slot", old_plugin_id, tier);
     Ok(())
@@ -50,6 +71,13 @@ pub fn uninstall_plugin(ctx: Context<UninstallPlugin>, tier: u8) -> Result<()> {
 pub struct UninstallPlugin<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+    )]
+    pub player_data: Account<'info, PlayerData>,
     
     /// Player's plugin slot for this tier
     #[account(
@@ -63,6 +91,20 @@ pub struct UninstallPlugin<'info> {
         has_one = player,
     )]
     pub player_plugin_slot: Account<'info, PlayerPluginSlot>,
+
+    /// Plugin currently installed in the slot
+    #[account(
+        mut,
+        seeds = [b"plugin", player_plugin_slot.plugin_id.to_le_bytes().as_ref()],
+        bump = plugin.bump,
+    )]
+    pub plugin: Account<'info, Plugin>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
     
     /// Player's cookie token account (to receive final cookies)
     #[account(
@@ -3,12 +3,17 @@ use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
 use crate::state::player_data::PlayerData;
 
 /// Unlock a plugin tier slot
 pub fn unlock_tier(ctx: Context<UnlockTier>, tier: u8) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_PLUGIN_ECONOMY)?;
+
     // Validate tier range
-    require!(tier >= 1 && tier <= 10, GameErrorCode::InvalidTier);
+    require!((1..=10).contains(&tier), GameErrorCode::InvalidTier);
 
     // Check if tier is already unlocked
     let tier_bit = 1u16 << (tier - 1);
@@ -17,8 +22,14 @@ pub fn unlock_tier(ctx: Context<UnlockTier>, tier: u8) -> Result<()> {
         GameErrorCode::TierAlreadyUnlocked
     );
 
+    // Check the player is high enough level for this tier
+    require!(
+        ctx.accounts.player_data.level >= ctx.accounts.game_config.required_level_for_tier(tier),
+        GameErrorCode::LevelTooLow
+    );
+
     // Calculate unlock cost (Tier 1 is free)
-    let unlock_cost = calculate_unlock_cost(tier);
+    let unlock_cost = calculate_unlock_cost(&ctx.accounts.game_config, tier)?;
 
     // Burn cookies if cost > 0
     if unlock_cost > 0 {
@@ -53,6 +64,12 @@ pub struct UnlockTier<'info> {
     )]
     pub player_data: Account<'info, PlayerData>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
     /// Player's cookie token account (to burn unlock cost)
     #[account(
         mut,
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+use crate::state::program_admin::ProgramAdmin;
+
+/// New values for the game config. Fields left as `None` keep their current value.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateConfigParams {
+    pub base_production_rate: Option<u64>,
+    pub creation_base_cost: Option<u64>,
+    pub install_base_cost: Option<u64>,
+    pub unlock_base_cost: Option<u64>,
+    pub creator_share_bps: Option<u16>,
+    pub burn_share_bps: Option<u16>,
+    pub max_energy: Option<u64>,
+    pub time_to_refill_energy: Option<i64>,
+    pub click_energy_cost: Option<u64>,
+    pub xp_per_click: Option<u64>,
+    pub xp_per_chop: Option<u64>,
+    pub xp_per_claim: Option<u64>,
+    pub level_xp_base: Option<u64>,
+    pub max_level: Option<u8>,
+    pub levels_per_tier: Option<u8>,
+    pub max_energy_per_level: Option<u64>,
+    pub click_power_per_tier: Option<u64>,
+    pub chop_energy_cost: Option<u64>,
+    pub wood_per_axe_level: Option<u64>,
+    pub axe_upgrade_base_cost: Option<u64>,
+    pub max_axe_level: Option<u8>,
+    pub combo_window_seconds: Option<i64>,
+    pub combo_step_clicks: Option<u32>,
+    pub combo_step_bonus_bps: Option<u32>,
+    pub max_combo_multiplier_bps: Option<u32>,
+    pub click_rate_window_slots: Option<u64>,
+    pub max_clicks_per_window: Option<u32>,
+    pub golden_cookie_interval_slots: Option<u64>,
+    pub golden_cookie_lifetime_slots: Option<u64>,
+    pub golden_cookie_lump_sum: Option<u64>,
+    pub golden_cookie_frenzy_chance: Option<u8>,
+    pub frenzy_duration_seconds: Option<i64>,
+    pub frenzy_multiplier_bps: Option<u32>,
+    pub daily_reward_base: Option<u64>,
+    pub daily_reward_per_streak_day: Option<u64>,
+    pub max_daily_streak_bonus_days: Option<u16>,
+    pub tree_hp: Option<u64>,
+    pub felling_bonus_wood: Option<u64>,
+    pub share_change_notice_seconds: Option<i64>,
+}
+
+/// Update the global game config (admin only)
+pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.game_config;
+
+    if let Some(v) = params.base_production_rate {
+        config.base_production_rate = v;
+    }
+    if let Some(v) = params.creation_base_cost {
+        config.creation_base_cost = v;
+    }
+    if let Some(v) = params.install_base_cost {
+        config.install_base_cost = v;
+    }
+    if let Some(v) = params.unlock_base_cost {
+        config.unlock_base_cost = v;
+    }
+    if let Some(v) = params.creator_share_bps {
+        config.creator_share_bps = v;
+    }
+    if let Some(v) = params.burn_share_bps {
+        config.burn_share_bps = v;
+    }
+    if let Some(v) = params.max_energy {
+        config.max_energy = v;
+    }
+    if let Some(v) = params.time_to_refill_energy {
+        config.time_to_refill_energy = v;
+    }
+    if let Some(v) = params.click_energy_cost {
+        config.click_energy_cost = v;
+    }
+    if let Some(v) = params.xp_per_click {
+        config.xp_per_click = v;
+    }
+    if let Some(v) = params.xp_per_chop {
+        config.xp_per_chop = v;
+    }
+    if let Some(v) = params.xp_per_claim {
+        config.xp_per_claim = v;
+    }
+    if let Some(v) = params.level_xp_base {
+        config.level_xp_base = v;
+    }
+    if let Some(v) = params.max_level {
+        config.max_level = v;
+    }
+    if let Some(v) = params.levels_per_tier {
+        config.levels_per_tier = v;
+    }
+    if let Some(v) = params.max_energy_per_level {
+        config.max_energy_per_level = v;
+    }
+    if let Some(v) = params.click_power_per_tier {
+        config.click_power_per_tier = v;
+    }
+    if let Some(v) = params.chop_energy_cost {
+        config.chop_energy_cost = v;
+    }
+    if let Some(v) = params.wood_per_axe_level {
+        config.wood_per_axe_level = v;
+    }
+    if let Some(v) = params.axe_upgrade_base_cost {
+        config.axe_upgrade_base_cost = v;
+    }
+    if let Some(v) = params.max_axe_level {
+        config.max_axe_level = v;
+    }
+    if let Some(v) = params.combo_window_seconds {
+        config.combo_window_seconds = v;
+    }
+    if let Some(v) = params.combo_step_clicks {
+        config.combo_step_clicks = v;
+    }
+    if let Some(v) = params.combo_step_bonus_bps {
+        config.combo_step_bonus_bps = v;
+    }
+    if let Some(v) = params.max_combo_multiplier_bps {
+        config.max_combo_multiplier_bps = v;
+    }
+    if let Some(v) = params.click_rate_window_slots {
+        config.click_rate_window_slots = v;
+    }
+    if let Some(v) = params.max_clicks_per_window {
+        config.max_clicks_per_window = v;
+    }
+    if let Some(v) = params.golden_cookie_interval_slots {
+        config.golden_cookie_interval_slots = v;
+    }
+    if let Some(v) = params.golden_cookie_lifetime_slots {
+        config.golden_cookie_lifetime_slots = v;
+    }
+    if let Some(v) = params.golden_cookie_lump_sum {
+        config.golden_cookie_lump_sum = v;
+    }
+    if let Some(v) = params.golden_cookie_frenzy_chance {
+        config.golden_cookie_frenzy_chance = v;
+    }
+    if let Some(v) = params.frenzy_duration_seconds {
+        config.frenzy_duration_seconds = v;
+    }
+    if let Some(v) = params.frenzy_multiplier_bps {
+        config.frenzy_multiplier_bps = v;
+    }
+    if let Some(v) = params.daily_reward_base {
+        config.daily_reward_base = v;
+    }
+    if let Some(v) = params.daily_reward_per_streak_day {
+        config.daily_reward_per_streak_day = v;
+    }
+    if let Some(v) = params.max_daily_streak_bonus_days {
+        config.max_daily_streak_bonus_days = v;
+    }
+    if let Some(v) = params.tree_hp {
+        config.tree_hp = v;
+    }
+    if let Some(v) = params.felling_bonus_wood {
+        config.felling_bonus_wood = v;
+    }
+    if let Some(v) = params.share_change_notice_seconds {
+        config.share_change_notice_seconds = v;
+    }
+
+    config.validate()?;
+
+    msg!("Game config updated");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+}
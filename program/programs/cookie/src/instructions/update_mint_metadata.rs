@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::GameErrorCode;
+use crate::state::program_admin::ProgramAdmin;
+
+// Import metadata types from mpl_token_metadata
+use mpl_token_metadata::instructions::{
+    UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs,
+};
+use mpl_token_metadata::types::DataV2;
+
+/// Update the cookie token's name, symbol and URI. The reward PDA is the
+/// update authority, so it signs the CPI. Setting `make_immutable` locks the
+/// metadata for good after this update.
+pub fn update_mint_metadata(
+    ctx: Context<UpdateMintMetadata>,
+    uri: String,
+    name: String,
+    symbol: String,
+    make_immutable: bool,
+) -> Result<()> {
+    // PDA seeds and bump to "sign" for CPI
+    let seeds = b"reward";
+    let bump = ctx.bumps.reward_token_mint;
+    let signer: &[&[&[u8]]] = &[&[seeds, &[bump]]];
+
+    let data_v2 = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    let metadata_infos = vec![
+        ctx.accounts.metadata_account.to_account_info(),
+        ctx.accounts.reward_token_mint.to_account_info(), // update authority
+    ];
+
+    let update_metadata_account_ix = UpdateMetadataAccountV2 {
+        metadata: ctx.accounts.metadata_account.key(),
+        update_authority: ctx.accounts.reward_token_mint.key(),
+    };
+
+    let args = UpdateMetadataAccountV2InstructionArgs {
+        data: Some(data_v2),
+        new_update_authority: None,
+        primary_sale_happened: None,
+        is_mutable: if make_immutable { Some(false) } else { None },
+    };
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &update_metadata_account_ix.instruction(args),
+        &metadata_infos,
+        signer,
+    )?;
+
+    if make_immutable {
+        msg!("Cookie metadata updated and locked");
+    } else {
+        msg!("Cookie metadata updated");
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMintMetadata<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    // The PDA is both the mint and the metadata update authority
+    #[account(
+        seeds = [b"reward"],
+        bump,
+    )]
+    pub reward_token_mint: Account<'info, Mint>,
+
+    ///CHECK: Using "seeds" constraint to validate metadata account address
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            reward_token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = mpl_token_metadata::ID,
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use mpl_token_metadata::instructions::{
+    UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs,
+};
+use mpl_token_metadata::types::DataV2;
+
+use crate::constants::*;
+use crate::errors::GameErrorCode;
+use crate::events::PluginUpdated;
+use crate::state::game_config::GameConfig;
+use crate::state::plugin::Plugin;
+
+/// Change a plugin's metadata URI and/or creator share (creator, or the NFT
+/// holder if the plugin has one).
+/// The metadata changes right away, on the plugin NFT too if it has one; a new
+/// share only applies after the config's notice period so installers see it
+/// coming.
+pub fn update_plugin(
+    ctx: Context<UpdatePlugin>,
+    metadata_uri: Option<String>,
+    creator_share_bps: Option<u16>,
+) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_PLUGIN_CREATION)?;
+    require_keys_eq!(
+        ctx.accounts
+            .plugin
+            .rights_holder(ctx.accounts.nft_holder_account.as_deref())?,
+        ctx.accounts.owner.key(),
+        GameErrorCode::Unauthorized
+    );
+
+    if let Some(uri) = &metadata_uri {
+        require!(uri.len() <= 200, GameErrorCode::MetadataUriTooLong);
+        if ctx.accounts.plugin.nft_mint.is_some() {
+            update_nft_metadata(&ctx, uri.clone())?;
+        }
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let plugin = &mut ctx.accounts.plugin;
+    plugin.apply_pending_share(now);
+
+    if let Some(uri) = metadata_uri {
+        plugin.metadata_uri = uri;
+    }
+
+    if let Some(share) = creator_share_bps {
+        require!(share <= 10000, GameErrorCode::InvalidRevenueShare);
+        if share == plugin.creator_share_bps {
+            // Cancels any pending change
+            plugin.pending_creator_share_bps = 0;
+            plugin.share_change_at = 0;
+        } else {
+            plugin.schedule_share_change(
+                share,
+                now,
+                ctx.accounts.game_config.share_change_notice_seconds,
+            );
+        }
+    }
+
+    emit!(PluginUpdated {
+        plugin_id: plugin.plugin_id,
+        metadata_uri: plugin.metadata_uri.clone(),
+        creator_share_bps: plugin.creator_share_bps,
+        pending_creator_share_bps: plugin.pending_creator_share_bps,
+        share_change_at: plugin.share_change_at,
+    });
+
+    msg!("Plugin {} updated", plugin.plugin_id);
+    Ok(())
+}
+
+/// Point the plugin NFT's metadata at the new URI. The mint PDA is the NFT's
+/// update authority, so it signs the CPI.
+fn update_nft_metadata(ctx: &Context<UpdatePlugin>, uri: String) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let (Some(nft_mint), Some(nft_metadata), Some(_token_metadata_program)) = (
+        &accounts.plugin_nft_mint,
+        &accounts.nft_metadata,
+        &accounts.token_metadata_program,
+    ) else {
+        return err!(GameErrorCode::MissingNftAccount);
+    };
+
+    let plugin_id = accounts.plugin.plugin_id;
+    let id_bytes = plugin_id.to_le_bytes();
+    let bump = ctx
+        .bumps
+        .plugin_nft_mint
+        .ok_or(GameErrorCode::MissingNftAccount)?;
+    let signer: &[&[&[u8]]] = &[&[b"plugin_nft", id_bytes.as_ref(), &[bump]]];
+
+    let data_v2 = DataV2 {
+        name: format!("Cookie Plugin #{}", plugin_id),
+        symbol: "PLUGIN".to_string(),
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    let update_metadata_ix = UpdateMetadataAccountV2 {
+        metadata: nft_metadata.key(),
+        update_authority: nft_mint.key(),
+    };
+    anchor_lang::solana_program::program::invoke_signed(
+        &update_metadata_ix.instruction(UpdateMetadataAccountV2InstructionArgs {
+            data: Some(data_v2),
+            new_update_authority: None,
+            primary_sale_happened: None,
+            is_mutable: None,
+        }),
+        &[
+            nft_metadata.to_account_info(),
+            nft_mint.to_account_info(), // update authority
+        ],
+        signer,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlugin<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"plugin", plugin.plugin_id.to_le_bytes().as_ref()],
+        bump = plugin.bump,
+    )]
+    pub plugin: Account<'info, Plugin>,
+
+    /// Token account holding the plugin NFT, required if the plugin has one
+    pub nft_holder_account: Option<Account<'info, TokenAccount>>,
+
+    /// The plugin NFT's mint, required to change the URI of a plugin with one
+    #[account(
+        seeds = [b"plugin_nft", plugin.plugin_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub plugin_nft_mint: Option<Account<'info, Mint>>,
+
+    /// CHECK: Metaplex only lets the mint PDA update its own NFT's metadata
+    #[account(mut)]
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: This is the Metaplex token metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+}
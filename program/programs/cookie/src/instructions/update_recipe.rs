@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+use crate::state::program_admin::ProgramAdmin;
+use crate::state::recipe::{Recipe, RecipeOutput};
+
+/// Change a recipe's costs and output, or disable it (admin only)
+pub fn update_recipe(
+    ctx: Context<UpdateRecipe>,
+    wood_cost: u64,
+    cookie_cost: u64,
+    output: RecipeOutput,
+    enabled: bool,
+) -> Result<()> {
+    output.validate()?;
+    require!(
+        wood_cost > 0 || cookie_cost > 0,
+        GameErrorCode::InvalidRecipe
+    );
+
+    let recipe = &mut ctx.accounts.recipe;
+    recipe.wood_cost = wood_cost;
+    recipe.cookie_cost = cookie_cost;
+    recipe.output = output;
+    recipe.enabled = enabled;
+
+    msg!("Recipe {} updated", recipe.recipe_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateRecipe<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin"],
+        bump = program_admin.bump,
+        constraint = program_admin.authority == admin.key() @ GameErrorCode::Unauthorized,
+    )]
+    pub program_admin: Account<'info, ProgramAdmin>,
+
+    #[account(
+        mut,
+        seeds = [b"recipe", recipe.recipe_id.to_le_bytes().as_ref()],
+        bump = recipe.bump,
+    )]
+    pub recipe: Account<'info, Recipe>,
+}
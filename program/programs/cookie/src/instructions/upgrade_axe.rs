@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
+use crate::state::player_data::PlayerData;
+
+/// Buy the next axe level with cookies
+pub fn upgrade_axe(ctx: Context<UpgradeAxe>) -> Result<()> {
+    ctx.accounts
+        .game_config
+        .require_not_paused(PAUSE_CHOPPING)?;
+
+    let level = ctx.accounts.player_data.axe_level.saturating_add(1);
+    require!(
+        level <= ctx.accounts.game_config.max_axe_level,
+        GameErrorCode::MaxAxeLevel
+    );
+
+    let upgrade_cost = calculate_axe_upgrade_cost(&ctx.accounts.game_config, level);
+
+    // Burn cookies from player
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.cookie_mint.to_account_info(),
+            from: ctx.accounts.player_token_account.to_account_info(),
+            authority: ctx.accounts.player.to_account_info(),
+        },
+    );
+    burn(burn_ctx, upgrade_cost)?;
+
+    ctx.accounts.player_data.axe_level = level;
+
+    msg!("Axe upgraded to level {}! Cost: {}", level, upgrade_cost);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpgradeAxe<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+    )]
+    pub player_data: Account<'info, PlayerData>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump,
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Player's cookie token account (to burn upgrade cost)
+    #[account(
+        mut,
+        associated_token::mint = cookie_mint,
+        associated_token::authority = player,
+    )]
+    pub player_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward"],
+        bump,
+    )]
+    pub cookie_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -3,9 +3,12 @@ pub use anchor_lang::prelude::*;
 pub use session_keys::{session_auth_or, Session, SessionError};
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 use instructions::*;
+use state::plugin::PluginStatus;
+use state::recipe::RecipeOutput;
 
 declare_id!("H9BK2gP55dKbadkAwroaTZo5L5vw3QuDtSLmE6WbWKE9");
 
@@ -18,6 +21,30 @@ pub mod cookie {
         init_player::init_player(ctx)
     }
 
+    pub fn init_admin(ctx: Context<InitAdmin>) -> Result<()> {
+        init_admin::init_admin(ctx)
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_authority: Option<Pubkey>) -> Result<()> {
+        propose_admin::propose_admin(ctx, new_authority)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin::accept_admin(ctx)
+    }
+
+    pub fn init_config(ctx: Context<InitConfig>) -> Result<()> {
+        init_config::init_config(ctx)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        update_config::update_config(ctx, params)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, flags: u8) -> Result<()> {
+        set_paused::set_paused(ctx, flags)
+    }
+
     pub fn create_mint(
         ctx: Context<CreateMint>,
         uri: String,
@@ -27,28 +54,131 @@ pub mod cookie {
         create_mint::create_mint(ctx, uri, name, symbol)
     }
 
+    pub fn create_wood_mint(
+        ctx: Context<CreateWoodMint>,
+        uri: String,
+        name: String,
+        symbol: String,
+    ) -> Result<()> {
+        create_wood_mint::create_wood_mint(ctx, uri, name, symbol)
+    }
+
+    pub fn update_mint_metadata(
+        ctx: Context<UpdateMintMetadata>,
+        uri: String,
+        name: String,
+        symbol: String,
+        make_immutable: bool,
+    ) -> Result<()> {
+        update_mint_metadata::update_mint_metadata(ctx, uri, name, symbol, make_immutable)
+    }
+
+    pub fn set_player_name(ctx: Context<SetPlayerName>, name: String) -> Result<()> {
+        set_player_name::set_player_name(ctx, name)
+    }
+
+    #[session_auth_or(
+        ctx.accounts.player.authority.key() == ctx.accounts.signer.key(),
+        GameErrorCode::WrongAuthority
+    )]
+    pub fn on_click(ctx: Context<OnClick>, counter: u16) -> Result<()> {
+        on_click::on_click(ctx, counter)
+    }
+
+    // Same as on_click but for many buffered clicks at once, so the client can
+    // flush clicks every few seconds instead of sending one transaction each.
     #[session_auth_or(
         ctx.accounts.player.authority.key() == ctx.accounts.signer.key(),
         GameErrorCode::WrongAuthority
     )]
-    pub fn on_click(ctx: Context<OnClick>) -> Result<()> {
-        on_click::on_click(ctx)
+    pub fn on_click_batch(ctx: Context<OnClick>, count: u16, counter: u16) -> Result<()> {
+        on_click_batch::on_click_batch(ctx, count, counter)
     }
 
-    // This function lets the player chop a tree and get 1 wood. The session_auth_or macro
-    // lets the player either use their session token or their main wallet. (The counter is only
-    // there so that the player can do multiple transactions in the same block. Without it multiple transactions
-    // in the same block would result in the same signature and therefore fail.)
+    // The golden cookie can be claimed with a session key too, since players
+    // have to be quick to catch it.
     #[session_auth_or(
         ctx.accounts.player.authority.key() == ctx.accounts.signer.key(),
         GameErrorCode::WrongAuthority
     )]
-    pub fn chop_tree(ctx: Context<ChopTree>, _level_seed: String, counter: u16) -> Result<()> {
-        chop_tree::chop_tree(ctx, counter, 1)
+    pub fn claim_golden_cookie(ctx: Context<ClaimGoldenCookie>) -> Result<()> {
+        claim_golden_cookie::claim_golden_cookie(ctx)
+    }
+
+    pub fn claim_daily_reward(ctx: Context<ClaimDailyReward>) -> Result<()> {
+        claim_daily_reward::claim_daily_reward(ctx)
+    }
+
+    // This function lets the player chop a tree `chops` times for wood. The session_auth_or macro
+    // lets the player either use their session token or their main wallet. (The counter makes
+    // every transaction unique, so the player can do multiple transactions in the same block, and
+    // it has to go up each time so old transactions can't be replayed. See PlayerData::use_nonce.)
+    #[session_auth_or(
+        ctx.accounts.player.authority.key() == ctx.accounts.signer.key(),
+        GameErrorCode::WrongAuthority
+    )]
+    pub fn chop_tree(
+        ctx: Context<ChopTree>,
+        _level_seed: String,
+        counter: u16,
+        chops: u16,
+    ) -> Result<()> {
+        chop_tree::chop_tree(ctx, counter, chops)
+    }
+
+    pub fn upgrade_axe(ctx: Context<UpgradeAxe>) -> Result<()> {
+        upgrade_axe::upgrade_axe(ctx)
+    }
+
+    // Crafting
+
+    pub fn create_recipe(
+        ctx: Context<CreateRecipe>,
+        recipe_id: u64,
+        wood_cost: u64,
+        cookie_cost: u64,
+        output: RecipeOutput,
+    ) -> Result<()> {
+        create_recipe::create_recipe(ctx, recipe_id, wood_cost, cookie_cost, output)
+    }
+
+    pub fn update_recipe(
+        ctx: Context<UpdateRecipe>,
+        wood_cost: u64,
+        cookie_cost: u64,
+        output: RecipeOutput,
+        enabled: bool,
+    ) -> Result<()> {
+        update_recipe::update_recipe(ctx, wood_cost, cookie_cost, output, enabled)
+    }
+
+    pub fn craft(ctx: Context<Craft>, recipe_id: u64) -> Result<()> {
+        craft::craft(ctx, recipe_id)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        migrate_account::migrate_account(ctx)
+    }
+
+    pub fn migrate_wood(ctx: Context<MigrateWood>) -> Result<()> {
+        migrate_wood::migrate_wood(ctx)
+    }
+
+    pub fn close_player<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClosePlayer<'info>>,
+    ) -> Result<()> {
+        close_player::close_player(ctx)
     }
 
     // Plugin System Instructions
     
+    pub fn init_plugin_registry<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitPluginRegistry<'info>>,
+        next_plugin_id: u64,
+    ) -> Result<()> {
+        init_plugin_registry::init_plugin_registry(ctx, next_plugin_id)
+    }
+
     pub fn create_plugin(
         ctx: Context<CreatePlugin>,
         tier: u8,
@@ -58,6 +188,26 @@ pub mod cookie {
         create_plugin::create_plugin(ctx, tier, metadata_uri, creator_share_bps)
     }
 
+    pub fn update_plugin(
+        ctx: Context<UpdatePlugin>,
+        metadata_uri: Option<String>,
+        creator_share_bps: Option<u16>,
+    ) -> Result<()> {
+        update_plugin::update_plugin(ctx, metadata_uri, creator_share_bps)
+    }
+
+    pub fn set_plugin_listing(ctx: Context<SetPluginListing>, listed: bool) -> Result<()> {
+        set_plugin_listing::set_plugin_listing(ctx, listed)
+    }
+
+    pub fn moderate_plugin(ctx: Context<ModeratePlugin>, status: PluginStatus) -> Result<()> {
+        moderate_plugin::moderate_plugin(ctx, status)
+    }
+
+    pub fn rate_plugin(ctx: Context<RatePlugin>, rating: u8) -> Result<()> {
+        rate_plugin::rate_plugin(ctx, rating)
+    }
+
     pub fn unlock_tier(ctx: Context<UnlockTier>, tier: u8) -> Result<()> {
         unlock_tier::unlock_tier(ctx, tier)
     }
@@ -0,0 +1,295 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::GameErrorCode;
+
+/// Global game configuration - singleton PDA seeded by "config"
+/// Holds every economic knob so the game can be retuned without a redeploy
+#[account]
+#[derive(InitSpace, Default)]
+pub struct GameConfig {
+    /// Account layout version
+    pub version: u8,
+
+    /// Tier 1 plugin production in cookies per hour
+    pub base_production_rate: u64,
+
+    /// Base cost to create a plugin
+    pub creation_base_cost: u64,
+
+    /// Base cost to install a plugin
+    pub install_base_cost: u64,
+
+    /// Base cost to unlock a tier
+    pub unlock_base_cost: u64,
+
+    /// Default revenue share to creator in basis points
+    pub creator_share_bps: u16,
+
+    /// Default revenue share burned in basis points
+    pub burn_share_bps: u16,
+
+    /// Maximum energy a player can hold
+    pub max_energy: u64,
+
+    /// Seconds it takes to refill one energy
+    pub time_to_refill_energy: i64,
+
+    /// Energy spent per cookie click
+    pub click_energy_cost: u64,
+
+    /// Bitmask of paused instruction families (see `PAUSE_*` constants)
+    pub paused: u8,
+
+    /// XP awarded per cookie click
+    pub xp_per_click: u64,
+
+    /// XP awarded per chop
+    pub xp_per_chop: u64,
+
+    /// XP awarded per plugin cookie claim
+    pub xp_per_claim: u64,
+
+    /// XP needed for level 2; level N needs `level_xp_base * (N - 1)^2`
+    pub level_xp_base: u64,
+
+    /// Highest reachable level
+    pub max_level: u8,
+
+    /// Levels needed per plugin tier (tier N needs level `1 + (N - 1) * levels_per_tier`)
+    pub levels_per_tier: u8,
+
+    /// Extra max energy per level above 1
+    pub max_energy_per_level: u64,
+
+    /// Click power added per tier of every installed plugin
+    pub click_power_per_tier: u64,
+
+    /// Energy spent per chop
+    pub chop_energy_cost: u64,
+
+    /// Extra wood per chop for each axe level
+    pub wood_per_axe_level: u64,
+
+    /// Cookie cost of the first axe upgrade, doubling for each level after
+    pub axe_upgrade_base_cost: u64,
+
+    /// Highest axe level
+    pub max_axe_level: u8,
+
+    /// Seconds between clicks before the combo resets
+    pub combo_window_seconds: i64,
+
+    /// Combo clicks per multiplier step
+    pub combo_step_clicks: u32,
+
+    /// Multiplier added per combo step in basis points
+    pub combo_step_bonus_bps: u32,
+
+    /// Highest combo multiplier in basis points (10000 = 1x)
+    pub max_combo_multiplier_bps: u32,
+
+    /// Length of the click rate limit window in slots
+    pub click_rate_window_slots: u64,
+
+    /// Most clicks a player can land in one rate limit window
+    pub max_clicks_per_window: u32,
+
+    /// Slots between golden cookie spawns
+    pub golden_cookie_interval_slots: u64,
+
+    /// Slots a golden cookie stays claimable after it spawns
+    pub golden_cookie_lifetime_slots: u64,
+
+    /// Cookies paid out by a lump sum golden cookie
+    pub golden_cookie_lump_sum: u64,
+
+    /// Chance (0-100) that a golden cookie is a frenzy instead of a lump sum
+    pub golden_cookie_frenzy_chance: u8,
+
+    /// How long a frenzy lasts in seconds
+    pub frenzy_duration_seconds: i64,
+
+    /// Production multiplier during a frenzy in basis points (10000 = 1x)
+    pub frenzy_multiplier_bps: u32,
+
+    /// Cookies for the first day of a daily reward streak
+    pub daily_reward_base: u64,
+
+    /// Extra cookies per consecutive day of the streak
+    pub daily_reward_per_streak_day: u64,
+
+    /// Streak length after which the daily reward stops growing
+    pub max_daily_streak_bonus_days: u16,
+
+    /// Wood in each tree before it falls
+    pub tree_hp: u64,
+
+    /// Bonus wood for the player who fells a tree
+    pub felling_bonus_wood: u64,
+
+    /// Seconds before a creator's new revenue share takes effect
+    pub share_change_notice_seconds: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl GameConfig {
+    pub const VERSION: u8 = 1;
+
+    /// Space needed for this account: 8 (discriminator) + fields
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Reset every knob to the defaults from `constants.rs`
+    pub fn set_defaults(&mut self) {
+        self.base_production_rate = PLUGIN_BASE_PRODUCTION_RATE;
+        self.creation_base_cost = PLUGIN_CREATION_BASE_COST;
+        self.install_base_cost = PLUGIN_INSTALL_BASE_COST;
+        self.unlock_base_cost = PLUGIN_UNLOCK_BASE_COST;
+        self.creator_share_bps = CREATOR_SHARE_BPS;
+        self.burn_share_bps = BURN_SHARE_BPS;
+        self.max_energy = MAX_ENERGY;
+        self.time_to_refill_energy = TIME_TO_REFILL_ENERGY;
+        self.click_energy_cost = CLICK_ENERGY_COST;
+        self.xp_per_click = XP_PER_CLICK;
+        self.xp_per_chop = XP_PER_CHOP;
+        self.xp_per_claim = XP_PER_CLAIM;
+        self.level_xp_base = LEVEL_XP_BASE;
+        self.max_level = MAX_LEVEL;
+        self.levels_per_tier = LEVELS_PER_TIER;
+        self.max_energy_per_level = MAX_ENERGY_PER_LEVEL;
+        self.click_power_per_tier = CLICK_POWER_PER_TIER;
+        self.chop_energy_cost = CHOP_ENERGY_COST;
+        self.wood_per_axe_level = WOOD_PER_AXE_LEVEL;
+        self.axe_upgrade_base_cost = AXE_UPGRADE_BASE_COST;
+        self.max_axe_level = MAX_AXE_LEVEL;
+        self.combo_window_seconds = COMBO_WINDOW_SECONDS;
+        self.combo_step_clicks = COMBO_STEP_CLICKS;
+        self.combo_step_bonus_bps = COMBO_STEP_BONUS_BPS;
+        self.max_combo_multiplier_bps = MAX_COMBO_MULTIPLIER_BPS;
+        self.click_rate_window_slots = CLICK_RATE_WINDOW_SLOTS;
+        self.max_clicks_per_window = MAX_CLICKS_PER_WINDOW;
+        self.golden_cookie_interval_slots = GOLDEN_COOKIE_INTERVAL_SLOTS;
+        self.golden_cookie_lifetime_slots = GOLDEN_COOKIE_LIFETIME_SLOTS;
+        self.golden_cookie_lump_sum = GOLDEN_COOKIE_LUMP_SUM;
+        self.golden_cookie_frenzy_chance = GOLDEN_COOKIE_FRENZY_CHANCE;
+        self.frenzy_duration_seconds = FRENZY_DURATION_SECONDS;
+        self.frenzy_multiplier_bps = FRENZY_MULTIPLIER_BPS;
+        self.daily_reward_base = DAILY_REWARD_BASE;
+        self.daily_reward_per_streak_day = DAILY_REWARD_PER_STREAK_DAY;
+        self.max_daily_streak_bonus_days = MAX_DAILY_STREAK_BONUS_DAYS;
+        self.tree_hp = MAX_WOOD_PER_TREE;
+        self.felling_bonus_wood = FELLING_BONUS_WOOD;
+        self.share_change_notice_seconds = PLUGIN_SHARE_CHANGE_NOTICE_SECONDS;
+    }
+
+    /// Total XP needed to reach a level
+    pub fn xp_for_level(&self, level: u8) -> u64 {
+        let steps = level.saturating_sub(1) as u64;
+        self.level_xp_base.saturating_mul(steps * steps)
+    }
+
+    /// Level a player needs before unlocking a plugin tier
+    pub fn required_level_for_tier(&self, tier: u8) -> u8 {
+        let level = 1 + (tier.saturating_sub(1) as u16) * self.levels_per_tier as u16;
+        level.min(u8::MAX as u16) as u8
+    }
+
+    /// Click reward multiplier for a combo, in basis points
+    pub fn combo_multiplier_bps(&self, combo: u32) -> u32 {
+        let steps = combo / self.combo_step_clicks;
+        10000u32
+            .saturating_add(steps.saturating_mul(self.combo_step_bonus_bps))
+            .min(self.max_combo_multiplier_bps)
+    }
+
+    /// Whether a golden cookie is live at `slot`, and which spawn it belongs to.
+    /// Spawn N appears at slot `N * interval` and lasts `lifetime` slots.
+    pub fn golden_cookie_round(&self, slot: u64) -> Option<u64> {
+        if slot % self.golden_cookie_interval_slots < self.golden_cookie_lifetime_slots {
+            Some(slot / self.golden_cookie_interval_slots)
+        } else {
+            None
+        }
+    }
+
+    /// Cookies paid for claiming the daily reward on day `streak` of a streak
+    pub fn daily_reward(&self, streak: u16) -> u64 {
+        let bonus_days = streak
+            .min(self.max_daily_streak_bonus_days)
+            .saturating_sub(1);
+        self.daily_reward_base
+            .saturating_add((bonus_days as u64).saturating_mul(self.daily_reward_per_streak_day))
+    }
+
+    /// Wood gained per chop with an axe of the given level
+    pub fn wood_per_chop(&self, axe_level: u8) -> u64 {
+        1 + (axe_level as u64).saturating_mul(self.wood_per_axe_level)
+    }
+
+    /// Energy cap for a player of the given level
+    pub fn max_energy_for_level(&self, level: u8) -> u64 {
+        let bonus = (level.saturating_sub(1) as u64).saturating_mul(self.max_energy_per_level);
+        self.max_energy.saturating_add(bonus)
+    }
+
+    /// Fail with `GamePaused` if the given instruction family is paused
+    pub fn require_not_paused(&self, flag: u8) -> Result<()> {
+        require!(self.paused & flag == 0, GameErrorCode::GamePaused);
+        Ok(())
+    }
+
+    /// Make sure the config can't put the game into a broken state
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.creator_share_bps as u32 + self.burn_share_bps as u32 == 10000,
+            GameErrorCode::InvalidRevenueShare
+        );
+        require!(self.max_energy > 0, GameErrorCode::InvalidConfig);
+        require!(self.time_to_refill_energy > 0, GameErrorCode::InvalidConfig);
+        require!(
+            self.click_energy_cost <= self.max_energy,
+            GameErrorCode::InvalidConfig
+        );
+        require!(self.level_xp_base > 0, GameErrorCode::InvalidConfig);
+        require!(self.max_level >= 1, GameErrorCode::InvalidConfig);
+        require!(
+            self.chop_energy_cost <= self.max_energy,
+            GameErrorCode::InvalidConfig
+        );
+        require!(self.combo_step_clicks > 0, GameErrorCode::InvalidConfig);
+        require!(
+            self.max_combo_multiplier_bps >= 10000,
+            GameErrorCode::InvalidConfig
+        );
+        require!(
+            self.click_rate_window_slots > 0,
+            GameErrorCode::InvalidConfig
+        );
+        require!(self.max_clicks_per_window > 0, GameErrorCode::InvalidConfig);
+        require!(
+            self.golden_cookie_interval_slots > 0
+                && self.golden_cookie_lifetime_slots <= self.golden_cookie_interval_slots,
+            GameErrorCode::InvalidConfig
+        );
+        require!(
+            self.golden_cookie_frenzy_chance <= 100,
+            GameErrorCode::InvalidConfig
+        );
+        require!(
+            self.frenzy_duration_seconds >= 0,
+            GameErrorCode::InvalidConfig
+        );
+        require!(
+            self.frenzy_multiplier_bps >= 10000,
+            GameErrorCode::InvalidConfig
+        );
+        require!(self.tree_hp > 0, GameErrorCode::InvalidConfig);
+        require!(
+            self.share_change_notice_seconds >= 0,
+            GameErrorCode::InvalidConfig
+        );
+        Ok(())
+    }
+}
@@ -1,29 +1,156 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::MAX_WOOD_PER_TREE;
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
 
 #[account]
+#[derive(InitSpace)]
 pub struct GameData {
+    /// Account layout version
+    pub version: u8,
+    /// Wood chopped on this level over all trees
     pub total_wood_collected: u64,
+    /// Number of the tree currently being chopped (0 for the first tree)
+    pub tree_index: u64,
+    /// Wood left in the current tree, it falls at 0
+    pub tree_hp: u64,
+    /// Player who delivered the final chop on the last felled tree
+    pub last_feller: Pubkey,
 }
 
 impl GameData {
-    pub fn on_tree_chopped(&mut self, amount_chopped: u64) -> Result<()> {
-        match self.total_wood_collected.checked_add(amount_chopped) {
-            Some(v) => {
-                if self.total_wood_collected >= MAX_WOOD_PER_TREE {
-                    self.total_wood_collected = 0;
-                    msg!("Tree successfully chopped. New Tree coming up.");
-                } else {
-                    self.total_wood_collected = v;
-                    msg!("Total wood chopped: {}", v);
-                }
-            }
-            None => {
-                msg!("The ever tree is completly chopped!");
-            }
-        };
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
 
+    /// Stamp a level that `init_if_needed` just created, or make sure an
+    /// existing one is on the current layout. Old layouts would otherwise be
+    /// read field by field into the new one, so they have to go through
+    /// `migrate_account` first.
+    pub fn init_or_require_current(&mut self, data_len: usize) -> Result<()> {
+        require!(data_len == Self::LEN, GameErrorCode::AccountNotMigrated);
+        // Every layout with a version header writes it, so 0 means brand new
+        if self.version == 0 {
+            self.version = Self::VERSION;
+        }
+        require!(
+            self.version == Self::VERSION,
+            GameErrorCode::AccountNotMigrated
+        );
         Ok(())
     }
+
+    /// Take `amount_chopped` HP off the current tree. Returns true when this
+    /// chop felled it, in which case `chopper` is recorded and a new tree
+    /// grows. Chops beyond the tree's remaining HP are lost.
+    pub fn on_tree_chopped(
+        &mut self,
+        config: &GameConfig,
+        amount_chopped: u64,
+        chopper: Pubkey,
+    ) -> Result<bool> {
+        // New levels (and levels from before trees had HP) start with a fresh tree
+        if self.tree_hp == 0 {
+            self.tree_hp = config.tree_hp;
+        }
+
+        self.total_wood_collected = self.total_wood_collected.saturating_add(amount_chopped);
+        self.tree_hp = self.tree_hp.saturating_sub(amount_chopped);
+
+        if self.tree_hp > 0 {
+            msg!("Tree {} has {} wood left", self.tree_index, self.tree_hp);
+            return Ok(false);
+        }
+
+        msg!(
+            "Tree {} successfully chopped. New Tree coming up.",
+            self.tree_index
+        );
+        self.last_feller = chopper;
+        self.tree_index = self.tree_index.saturating_add(1);
+        self.tree_hp = config.tree_hp;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn config() -> GameConfig {
+        let mut config = GameConfig::default();
+        config.set_defaults();
+        config
+    }
+
+    fn game_data() -> GameData {
+        GameData {
+            version: GameData::VERSION,
+            total_wood_collected: 0,
+            tree_index: 0,
+            tree_hp: 0,
+            last_feller: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn chop_leaving_one_hp_does_not_fell_the_tree() {
+        let config = config();
+        let mut game_data = game_data();
+        let chopper = Pubkey::new_unique();
+
+        let felled = game_data
+            .on_tree_chopped(&config, MAX_WOOD_PER_TREE - 1, chopper)
+            .unwrap();
+
+        assert!(!felled);
+        assert_eq!(game_data.tree_hp, 1);
+        assert_eq!(game_data.tree_index, 0);
+        assert_eq!(game_data.last_feller, Pubkey::default());
+    }
+
+    #[test]
+    fn final_chop_fells_the_tree_and_records_the_chopper() {
+        let config = config();
+        let mut game_data = game_data();
+        let chopper = Pubkey::new_unique();
+        game_data
+            .on_tree_chopped(&config, MAX_WOOD_PER_TREE - 1, Pubkey::new_unique())
+            .unwrap();
+
+        let felled = game_data.on_tree_chopped(&config, 1, chopper).unwrap();
+
+        assert!(felled);
+        assert_eq!(game_data.last_feller, chopper);
+        assert_eq!(game_data.tree_index, 1);
+        assert_eq!(game_data.tree_hp, MAX_WOOD_PER_TREE);
+        assert_eq!(game_data.total_wood_collected, MAX_WOOD_PER_TREE);
+    }
+
+    #[test]
+    fn overkill_chop_fells_only_one_tree() {
+        let config = config();
+        let mut game_data = game_data();
+
+        let felled = game_data
+            .on_tree_chopped(&config, MAX_WOOD_PER_TREE + 5, Pubkey::new_unique())
+            .unwrap();
+
+        assert!(felled);
+        assert_eq!(game_data.tree_index, 1);
+        assert_eq!(game_data.tree_hp, MAX_WOOD_PER_TREE);
+    }
+
+    #[test]
+    fn only_new_or_current_levels_are_accepted() {
+        let mut fresh = game_data();
+        fresh.version = 0;
+        fresh.init_or_require_current(GameData::LEN).unwrap();
+        assert_eq!(fresh.version, GameData::VERSION);
+
+        let mut old = game_data();
+        old.version = GameData::VERSION + 1;
+        assert!(old.init_or_require_current(GameData::LEN).is_err());
+        assert!(game_data().init_or_require_current(1000).is_err());
+    }
 }
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Golden cookie tracker - singleton PDA seeded by "golden_cookie"
+/// A golden cookie spawns every `golden_cookie_interval_slots` (see
+/// `GameConfig::golden_cookie_round`); this account only remembers which spawn
+/// was claimed last so each one pays out once
+#[account]
+#[derive(InitSpace)]
+pub struct GoldenCookie {
+    /// Spawn number of the last claimed golden cookie
+    pub last_claimed_round: u64,
+
+    /// Player who claimed it
+    pub last_claimed_by: Pubkey,
+
+    /// Number of golden cookies claimed so far
+    pub total_claims: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl GoldenCookie {
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Whether the golden cookie of `round` can still be claimed
+    pub fn is_claimable(&self, round: u64) -> bool {
+        // A fresh account has claimed nothing yet
+        self.total_claims == 0 || round > self.last_claimed_round
+    }
+}
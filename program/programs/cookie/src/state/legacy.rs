@@ -0,0 +1,160 @@
+//! Account layouts from before the `version` header was added.
+//! Only used by `migrate_account` to read old accounts.
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_WOOD_PER_TREE;
+use crate::state::game_data::GameData;
+use crate::state::player_data::PlayerData;
+use crate::state::player_plugin_slot::PlayerPluginSlot;
+use crate::state::plugin::{Plugin, PluginStatus};
+
+#[derive(AnchorDeserialize)]
+pub struct PlayerDataV0 {
+    pub authority: Pubkey,
+    pub name: String,
+    pub level: u8,
+    pub xp: u64,
+    pub wood: u64,
+    pub energy: u64,
+    pub last_login: i64,
+    pub last_id: u16,
+    pub unlocked_tiers: u16,
+    pub plugin_global_counter: u64,
+}
+
+impl PlayerDataV0 {
+    /// `init_player` used a fixed `space = 1000`
+    pub const LEN: usize = 1000;
+}
+
+impl From<PlayerDataV0> for PlayerData {
+    fn from(old: PlayerDataV0) -> Self {
+        PlayerData {
+            version: PlayerData::VERSION,
+            authority: old.authority,
+            name: old.name,
+            level: old.level,
+            xp: old.xp,
+            wood: old.wood,
+            energy: old.energy,
+            last_login: old.last_login,
+            last_id: old.last_id,
+            unlocked_tiers: old.unlocked_tiers,
+            plugin_global_counter: old.plugin_global_counter,
+            total_clicks: 0,
+            // Not tracked before; migrate_account marks the tiers again as
+            // the player's occupied slots are migrated
+            installed_tiers: 0,
+            click_power: 1,
+            combo: 0,
+            last_click_at: 0,
+            frenzy_start: 0,
+            frenzy_end: 0,
+            frenzy_multiplier_bps: 0,
+            click_window_start: 0,
+            clicks_in_window: 0,
+            daily_streak: 0,
+            last_daily_claim_day: 0,
+            axe_level: 0,
+            used_plugins: 0,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct GameDataV0 {
+    pub total_wood_collected: u64,
+}
+
+impl GameDataV0 {
+    /// `init_player` and `chop_tree` used a fixed `space = 1000`
+    pub const LEN: usize = 1000;
+}
+
+impl From<GameDataV0> for GameData {
+    fn from(old: GameDataV0) -> Self {
+        GameData {
+            version: GameData::VERSION,
+            total_wood_collected: old.total_wood_collected,
+            tree_index: 0,
+            // Keep the progress on the current tree
+            tree_hp: MAX_WOOD_PER_TREE.saturating_sub(old.total_wood_collected),
+            last_feller: Pubkey::default(),
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct PluginV0 {
+    pub plugin_id: u64,
+    pub creator: Pubkey,
+    pub tier: u8,
+    pub metadata_uri: String,
+    pub total_installs: u64,
+    pub creator_earnings: u64,
+    pub created_at: i64,
+    pub creator_share_bps: u16,
+    pub burn_share_bps: u16,
+    pub bump: u8,
+}
+
+impl PluginV0 {
+    pub const LEN: usize = 8 + 8 + 32 + 1 + 4 + 200 + 8 + 8 + 8 + 2 + 2 + 1;
+}
+
+impl From<PluginV0> for Plugin {
+    fn from(old: PluginV0) -> Self {
+        Plugin {
+            version: Plugin::VERSION,
+            plugin_id: old.plugin_id,
+            creator: old.creator,
+            tier: old.tier,
+            metadata_uri: old.metadata_uri,
+            total_installs: old.total_installs,
+            creator_earnings: old.creator_earnings,
+            created_at: old.created_at,
+            creator_share_bps: old.creator_share_bps,
+            burn_share_bps: old.burn_share_bps,
+            bump: old.bump,
+            pending_creator_share_bps: 0,
+            share_change_at: 0,
+            status: PluginStatus::Active,
+            rating_sum: 0,
+            rating_count: 0,
+            nft_mint: None,
+            // Not tracked before; migrate_account counts them again as the
+            // slots that have the plugin installed are migrated
+            active_installs: 0,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct PlayerPluginSlotV0 {
+    pub player: Pubkey,
+    pub tier: u8,
+    pub plugin_id: u64,
+    pub installed_at: i64,
+    pub last_claim: i64,
+    pub total_claimed: u64,
+    pub bump: u8,
+}
+
+impl PlayerPluginSlotV0 {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 8 + 8 + 8 + 1;
+}
+
+impl From<PlayerPluginSlotV0> for PlayerPluginSlot {
+    fn from(old: PlayerPluginSlotV0) -> Self {
+        PlayerPluginSlot {
+            version: PlayerPluginSlot::VERSION,
+            player: old.player,
+            tier: old.tier,
+            plugin_id: old.plugin_id,
+            installed_at: old.installed_at,
+            last_claim: old.last_claim,
+            total_claimed: old.total_claimed,
+            bump: old.bump,
+        }
+    }
+}
@@ -1,4 +1,13 @@
+pub mod game_config;
 pub mod game_data;
+pub mod golden_cookie;
+pub mod legacy;
 pub mod player_data;
+pub mod player_item;
+pub mod player_name;
 pub mod plugin;
+pub mod plugin_rating;
+pub mod plugin_registry;
+pub mod program_admin;
 pub mod player_plugin_slot;
+pub mod recipe;
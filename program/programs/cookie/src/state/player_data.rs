@@ -1,15 +1,23 @@
-use crate::constants::*;
+use crate::constants::{calculate_click_power, SECONDS_PER_DAY};
+use crate::errors::GameErrorCode;
+use crate::state::game_config::GameConfig;
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(InitSpace, Default)]
 pub struct PlayerData {
+    /// Account layout version, bumped when fields are added (see migrate_account)
+    pub version: u8,
     pub authority: Pubkey,
+    #[max_len(32)]
     pub name: String,
     pub level: u8,
     pub xp: u64,
+    /// Wood chopped before wood became a token, see `migrate_wood`
     pub wood: u64,
     pub energy: u64,
     pub last_login: i64,
+    /// Last nonce used by `chop_tree` / `on_click`, see `use_nonce`
     pub last_id: u16,
     
     // Plugin system fields
@@ -17,64 +25,506 @@ pub struct PlayerData {
     /// Tier 1 is unlocked by default (bit 0 = 1)
     pub unlocked_tiers: u16,
     
-    /// Global counter for creating unique plugin IDs
+    /// Number of plugins this player created, plus one. Plugin IDs come from
+    /// the global `PluginRegistry`; this used to be a per-player ID counter.
     pub plugin_global_counter: u64,
+
+    /// Lifetime number of cookie clicks
+    pub total_clicks: u64,
+
+    /// Bitmask of tiers with a plugin installed (bits 0-9 for tiers 1-10)
+    pub installed_tiers: u16,
+
+    /// Cookies minted per click, cached from `installed_tiers`
+    pub click_power: u64,
+
+    /// Consecutive clicks within the combo window
+    pub combo: u32,
+
+    /// Timestamp of the last click (for the combo window)
+    pub last_click_at: i64,
+
+    /// Start of the current (or last) golden cookie frenzy or crafted boost
+    pub frenzy_start: i64,
+
+    /// End of the current (or last) golden cookie frenzy
+    pub frenzy_end: i64,
+
+    /// Production multiplier of that frenzy in basis points
+    pub frenzy_multiplier_bps: u32,
+
+    /// First slot of the current click rate limit window
+    pub click_window_start: u64,
+
+    /// Clicks landed in the current click rate limit window
+    pub clicks_in_window: u32,
+
+    /// Consecutive UTC days the daily reward was claimed
+    pub daily_streak: u16,
+
+    /// UTC day number (days since the unix epoch) of the last daily reward
+    pub last_daily_claim_day: i64,
+
+    /// Axe upgrade level (0 = starter axe), raises wood per chop
+    pub axe_level: u8,
+
+    /// Distinct plugins this player has installed, one `PluginRating` each
+    pub used_plugins: u32,
 }
 
 impl PlayerData {
+    pub const VERSION: u8 = 1;
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
     pub fn print(&mut self) -> Result<()> {
         // Note that logging costs a lot of compute. So don't use it too much.
-        msg!(
-            "Authority: {} Wood: {} Energy: {}",
-            self.authority,
-            self.wood,
-            self.energy
-        );
+        msg!("Authority: {} Energy: {}", self.authority, self.energy);
         Ok(())
     }
 
-    pub fn update_energy(&mut self) -> Result<()> {
+    pub fn update_energy(&mut self, config: &GameConfig) -> Result<()> {
         // Get the current timestamp
         let current_timestamp = Clock::get()?.unix_timestamp;
+        self.refill_energy(config, current_timestamp);
+        Ok(())
+    }
 
-        // Calculate the time passed since the last login
-        let mut time_passed: i64 = current_timestamp - self.last_login;
+    /// Refill one energy per full `time_to_refill_energy` interval since
+    /// `last_login`. The partial interval is kept so no time is lost.
+    pub fn refill_energy(&mut self, config: &GameConfig, current_timestamp: i64) {
+        let max_energy = config.max_energy_for_level(self.level);
 
-        // Calculate the time spent refilling energy
-        let mut time_spent = 0;
+        // Nothing to refill, restart the timer
+        if self.energy >= max_energy {
+            self.last_login = current_timestamp;
+            return;
+        }
 
-        while time_passed >= TIME_TO_REFILL_ENERGY && self.energy < MAX_ENERGY {
-            self.energy += 1;
-            time_passed -= TIME_TO_REFILL_ENERGY;
-            time_spent += TIME_TO_REFILL_ENERGY;
+        // Clock skew: never refill for time that hasn't happened yet
+        if current_timestamp <= self.last_login {
+            self.last_login = current_timestamp;
+            return;
         }
 
-        if self.energy >= MAX_ENERGY {
+        let time_passed = current_timestamp - self.last_login;
+        let intervals = (time_passed / config.time_to_refill_energy) as u64;
+        let missing = max_energy - self.energy;
+
+        if intervals >= missing {
+            self.energy = max_energy;
             self.last_login = current_timestamp;
         } else {
-            self.last_login += time_spent;
+            self.energy += intervals;
+            self.last_login += intervals as i64 * config.time_to_refill_energy;
+        }
+    }
+
+    /// Accept `counter` only if it comes after `last_id`, so a stale or replayed
+    /// transaction can't land. Counters wrap around: anything up to half the
+    /// u16 range ahead of `last_id` counts as newer.
+    pub fn use_nonce(&mut self, counter: u16) -> Result<()> {
+        let ahead = counter.wrapping_sub(self.last_id);
+        require!(
+            ahead != 0 && ahead <= u16::MAX / 2,
+            GameErrorCode::StaleNonce
+        );
+        self.last_id = counter;
+        Ok(())
+    }
+
+    /// Add XP and raise the level for every threshold crossed
+    pub fn add_xp(&mut self, config: &GameConfig, amount: u64) -> Result<()> {
+        self.xp = self.xp.saturating_add(amount);
+
+        // Accounts from before levels existed start at level 0
+        let old_level = self.level.max(1);
+        let mut level = old_level;
+        while level < config.max_level && self.xp >= config.xp_for_level(level + 1) {
+            level += 1;
         }
+        self.level = level;
 
+        if level > old_level {
+            msg!("Level up! You are now level {}", level);
+        }
         Ok(())
     }
 
-    pub fn chop_tree(&mut self, amount: u64) -> Result<()> {
-        match self.wood.checked_add(amount) {
-            Some(v) => {
-                self.wood = v;
-            }
-            None => {
-                msg!("Total wood reached!");
-            }
-        };
-        match self.energy.checked_sub(amount) {
-            Some(v) => {
-                self.energy = v;
-            }
-            None => {
-                self.energy = 0;
-            }
-        };
+    /// Count clicks towards the combo, resetting it if the window lapsed.
+    /// Returns the reward multiplier in basis points.
+    pub fn register_clicks(&mut self, config: &GameConfig, count: u32, now: i64) -> u32 {
+        if now.saturating_sub(self.last_click_at) > config.combo_window_seconds {
+            self.combo = 0;
+        }
+        self.combo = self.combo.saturating_add(count);
+        self.last_click_at = now;
+        config.combo_multiplier_bps(self.combo)
+    }
+
+    /// Count clicks against the per-window rate limit, starting a new window
+    /// once the old one is over
+    pub fn check_click_rate(&mut self, config: &GameConfig, count: u32, slot: u64) -> Result<()> {
+        let window_end = self
+            .click_window_start
+            .saturating_add(config.click_rate_window_slots);
+        if slot >= window_end {
+            self.click_window_start = slot;
+            self.clicks_in_window = 0;
+        }
+        let clicks = self.clicks_in_window.saturating_add(count);
+        require!(
+            clicks <= config.max_clicks_per_window,
+            GameErrorCode::ClickRateExceeded
+        );
+        self.clicks_in_window = clicks;
         Ok(())
     }
+
+    /// Record a daily reward claim at `now` and return the new streak.
+    /// Claiming on the day after the last claim extends the streak, skipping a
+    /// day starts over at 1.
+    pub fn register_daily_claim(&mut self, now: i64) -> Result<u16> {
+        let today = now.div_euclid(SECONDS_PER_DAY);
+        require!(
+            self.daily_streak == 0 || today > self.last_daily_claim_day,
+            GameErrorCode::DailyRewardAlreadyClaimed
+        );
+
+        if self.daily_streak > 0 && today == self.last_daily_claim_day + 1 {
+            self.daily_streak = self.daily_streak.saturating_add(1);
+        } else {
+            self.daily_streak = 1;
+        }
+        self.last_daily_claim_day = today;
+        Ok(self.daily_streak)
+    }
+
+    /// Start a golden cookie frenzy, replacing any frenzy still running
+    pub fn start_frenzy(&mut self, config: &GameConfig, now: i64) {
+        self.start_production_boost(
+            config.frenzy_multiplier_bps,
+            config.frenzy_duration_seconds,
+            now,
+        );
+    }
+
+    /// Boost production for `duration_seconds`, replacing any boost or frenzy
+    /// still running
+    pub fn start_production_boost(&mut self, multiplier_bps: u32, duration_seconds: i64, now: i64) {
+        self.frenzy_start = now;
+        self.frenzy_end = now.saturating_add(duration_seconds);
+        self.frenzy_multiplier_bps = multiplier_bps;
+    }
+
+    /// Add energy up to the cap for the player's level
+    pub fn add_energy(&mut self, config: &GameConfig, amount: u64) {
+        let max_energy = config.max_energy_for_level(self.level);
+        if self.energy < max_energy {
+            self.energy = self.energy.saturating_add(amount).min(max_energy);
+        }
+    }
+
+    /// Frenzy multiplier in basis points at `now` (10000 when no frenzy is running)
+    pub fn frenzy_multiplier_at(&self, now: i64) -> u32 {
+        if now >= self.frenzy_start && now < self.frenzy_end {
+            self.frenzy_multiplier_bps.max(10000)
+        } else {
+            10000
+        }
+    }
+
+    /// Mark a tier slot as occupied or empty and recompute click power
+    pub fn set_tier_installed(&mut self, config: &GameConfig, tier: u8, installed: bool) {
+        let tier_bit = 1u16 << (tier - 1);
+        if installed {
+            self.installed_tiers |= tier_bit;
+        } else {
+            self.installed_tiers &= !tier_bit;
+        }
+        self.click_power = calculate_click_power(config, self.installed_tiers);
+    }
+
+    /// Spend energy on `chops` chops and return the wood they yield. The wood
+    /// itself is minted as tokens by the `chop_tree` instruction.
+    pub fn chop_tree(&mut self, config: &GameConfig, chops: u16) -> Result<u64> {
+        require!(chops > 0, GameErrorCode::InvalidChopCount);
+
+        let energy_cost = config.chop_energy_cost.saturating_mul(chops as u64);
+        self.energy = self
+            .energy
+            .checked_sub(energy_cost)
+            .ok_or(GameErrorCode::NotEnoughEnergy)?;
+
+        Ok(config
+            .wood_per_chop(self.axe_level)
+            .saturating_mul(chops as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+
+    fn config() -> GameConfig {
+        let mut config = GameConfig::default();
+        config.set_defaults();
+        config
+    }
+
+    fn player(energy: u64, last_login: i64) -> PlayerData {
+        PlayerData {
+            level: 1,
+            energy,
+            last_login,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn refills_one_energy_per_interval_and_keeps_remainder() {
+        let config = config();
+        let mut player = player(10, 1_000);
+
+        player.refill_energy(&config, 1_000 + 3 * TIME_TO_REFILL_ENERGY + 25);
+
+        assert_eq!(player.energy, 13);
+        assert_eq!(player.last_login, 1_000 + 3 * TIME_TO_REFILL_ENERGY);
+    }
+
+    #[test]
+    fn partial_interval_refills_nothing() {
+        let config = config();
+        let mut player = player(10, 1_000);
+
+        player.refill_energy(&config, 1_000 + TIME_TO_REFILL_ENERGY - 1);
+
+        assert_eq!(player.energy, 10);
+        assert_eq!(player.last_login, 1_000);
+    }
+
+    #[test]
+    fn long_idle_period_caps_at_max_energy() {
+        let config = config();
+        let mut player = player(0, 0);
+        let now = 365 * 24 * 60 * 60;
+
+        player.refill_energy(&config, now);
+
+        assert_eq!(player.energy, MAX_ENERGY);
+        assert_eq!(player.last_login, now);
+    }
+
+    #[test]
+    fn full_energy_restarts_timer() {
+        let config = config();
+        let mut player = player(MAX_ENERGY, 1_000);
+
+        player.refill_energy(&config, 5_000);
+
+        assert_eq!(player.energy, MAX_ENERGY);
+        assert_eq!(player.last_login, 5_000);
+    }
+
+    #[test]
+    fn last_login_ahead_of_clock_refills_nothing() {
+        let config = config();
+        let mut player = player(10, 10_000);
+
+        player.refill_energy(&config, 1_000);
+
+        assert_eq!(player.energy, 10);
+        assert_eq!(player.last_login, 1_000);
+
+        // Refill resumes normally from the corrected timestamp
+        player.refill_energy(&config, 1_000 + TIME_TO_REFILL_ENERGY);
+        assert_eq!(player.energy, 11);
+    }
+
+    #[test]
+    fn combo_grows_within_window_and_resets_after() {
+        let config = config();
+        let mut player = player(0, 0);
+
+        // 10 clicks in a row reach the first multiplier step
+        let mut multiplier = 0;
+        for i in 0..10 {
+            multiplier = player.register_clicks(&config, 1, 1_000 + i);
+        }
+        assert_eq!(player.combo, 10);
+        assert_eq!(multiplier, 10000 + COMBO_STEP_BONUS_BPS);
+
+        // A pause longer than the window starts over
+        let multiplier = player.register_clicks(&config, 1, 1_009 + COMBO_WINDOW_SECONDS + 1);
+        assert_eq!(player.combo, 1);
+        assert_eq!(multiplier, 10000);
+    }
+
+    #[test]
+    fn combo_multiplier_is_capped() {
+        let config = config();
+        let mut player = player(0, 0);
+
+        let multiplier = player.register_clicks(&config, 1_000, 1_000);
+
+        assert_eq!(multiplier, MAX_COMBO_MULTIPLIER_BPS);
+    }
+
+    #[test]
+    fn higher_level_raises_the_cap() {
+        let config = config();
+        let mut player = player(0, 0);
+        player.level = 3;
+
+        player.refill_energy(&config, 365 * 24 * 60 * 60);
+
+        assert_eq!(player.energy, MAX_ENERGY + 2 * MAX_ENERGY_PER_LEVEL);
+    }
+
+    #[test]
+    fn frenzy_only_boosts_while_running() {
+        let config = config();
+        let mut player = player(10, 1_000);
+
+        player.start_frenzy(&config, 1_000);
+
+        assert_eq!(player.frenzy_multiplier_at(999), 10000);
+        assert_eq!(player.frenzy_multiplier_at(1_000), FRENZY_MULTIPLIER_BPS);
+        assert_eq!(
+            player.frenzy_multiplier_at(1_000 + FRENZY_DURATION_SECONDS),
+            10000
+        );
+    }
+
+    #[test]
+    fn click_rate_limit_resets_with_the_window() {
+        let config = config();
+        let mut player = player(0, 0);
+
+        player
+            .check_click_rate(&config, MAX_CLICKS_PER_WINDOW, 100)
+            .unwrap();
+        assert!(player.check_click_rate(&config, 1, 101).is_err());
+        assert_eq!(player.clicks_in_window, MAX_CLICKS_PER_WINDOW);
+
+        player
+            .check_click_rate(&config, 1, 100 + CLICK_RATE_WINDOW_SLOTS)
+            .unwrap();
+        assert_eq!(player.clicks_in_window, 1);
+    }
+
+    #[test]
+    fn daily_streak_grows_on_consecutive_days_and_resets_after_a_gap() {
+        let mut player = player(0, 0);
+        let day = SECONDS_PER_DAY;
+
+        assert_eq!(player.register_daily_claim(10 * day + 5).unwrap(), 1);
+        assert!(player.register_daily_claim(11 * day - 1).is_err());
+        assert_eq!(player.register_daily_claim(11 * day).unwrap(), 2);
+        assert_eq!(player.register_daily_claim(12 * day + 100).unwrap(), 3);
+        assert_eq!(player.register_daily_claim(14 * day).unwrap(), 1);
+    }
+
+    #[test]
+    fn daily_reward_bonus_is_capped() {
+        let config = config();
+
+        assert_eq!(config.daily_reward(1), DAILY_REWARD_BASE);
+        assert_eq!(
+            config.daily_reward(2),
+            DAILY_REWARD_BASE + DAILY_REWARD_PER_STREAK_DAY
+        );
+        assert_eq!(
+            config.daily_reward(100),
+            config.daily_reward(MAX_DAILY_STREAK_BONUS_DAYS)
+        );
+    }
+
+    #[test]
+    fn added_energy_is_capped_at_max_energy() {
+        let config = config();
+        let mut player = player(MAX_ENERGY - 3, 0);
+
+        player.add_energy(&config, 10);
+
+        assert_eq!(player.energy, MAX_ENERGY);
+    }
+
+    #[test]
+    fn click_power_adds_each_occupied_tier() {
+        let mut config = config();
+        assert_eq!(calculate_click_power(&config, 0), 1);
+        assert_eq!(calculate_click_power(&config, 0b11_1111_1111), 56);
+        // Bits past tier 10 aren't tiers
+        assert_eq!(calculate_click_power(&config, 0b1111_1100_0000_0000), 1);
+
+        config.click_power_per_tier = 3;
+        assert_eq!(calculate_click_power(&config, 0b110), 1 + (2 + 3) * 3);
+    }
+
+    #[test]
+    fn installing_and_removing_tiers_updates_click_power() {
+        let config = config();
+        let mut player = player(0, 0);
+
+        player.set_tier_installed(&config, 1, true);
+        player.set_tier_installed(&config, 10, true);
+        assert_eq!(player.installed_tiers, 0b10_0000_0001);
+        assert_eq!(player.click_power, 12);
+
+        // Installing into an occupied tier changes nothing
+        player.set_tier_installed(&config, 10, true);
+        assert_eq!(player.click_power, 12);
+
+        player.set_tier_installed(&config, 1, false);
+        assert_eq!(player.installed_tiers, 0b10_0000_0000);
+        assert_eq!(player.click_power, 11);
+    }
+
+    #[test]
+    fn axe_level_raises_wood_but_not_energy_cost() {
+        let config = config();
+        let mut player = player(10, 0);
+        player.axe_level = 2;
+
+        let wood = player.chop_tree(&config, 3).unwrap();
+
+        assert_eq!(wood, 3 * (1 + 2 * WOOD_PER_AXE_LEVEL));
+        assert_eq!(player.energy, 10 - 3 * CHOP_ENERGY_COST);
+    }
+
+    #[test]
+    fn chopping_more_than_energy_allows_fails() {
+        let config = config();
+        let mut player = player(2, 0);
+
+        assert!(player.chop_tree(&config, 3).is_err());
+        assert!(player.chop_tree(&config, 0).is_err());
+        assert_eq!(player.energy, 2);
+    }
+
+    #[test]
+    fn nonce_must_increase() {
+        let mut player = player(0, 0);
+
+        player.use_nonce(1).unwrap();
+        player.use_nonce(5).unwrap();
+
+        assert!(player.use_nonce(5).is_err());
+        assert!(player.use_nonce(4).is_err());
+        assert_eq!(player.last_id, 5);
+    }
+
+    #[test]
+    fn nonce_wraps_around() {
+        let mut player = player(0, 0);
+        player.last_id = u16::MAX - 1;
+
+        player.use_nonce(u16::MAX).unwrap();
+        player.use_nonce(0).unwrap();
+        player.use_nonce(3).unwrap();
+
+        assert!(player.use_nonce(u16::MAX).is_err());
+        assert_eq!(player.last_id, 3);
+    }
 }
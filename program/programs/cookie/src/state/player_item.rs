@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Crafted items a player owns of one kind - PDA seeded by "item", player and item_id
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerItem {
+    /// Player who owns the items
+    pub owner: Pubkey,
+
+    /// Item kind (see `RecipeOutput::Item`)
+    pub item_id: u32,
+
+    /// Number of items owned
+    pub amount: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PlayerItem {
+    /// Space needed for this account: 8 (discriminator) + fields
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
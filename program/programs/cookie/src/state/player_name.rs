@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_NAME_LEN, MIN_NAME_LEN};
+use crate::errors::GameErrorCode;
+
+/// Name registry entry - PDA seeded by the lowercase name
+/// Makes sure no two players share a display name
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerName {
+    /// Player authority that owns this name
+    pub owner: Pubkey,
+
+    /// The name as the player typed it
+    #[max_len(32)]
+    pub name: String,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PlayerName {
+    /// Space needed for this account: 8 (discriminator) + fields
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Names are 3-32 characters of ASCII letters, digits, `_` and `-`
+    pub fn validate(name: &str) -> Result<()> {
+        require!(
+            (MIN_NAME_LEN..=MAX_NAME_LEN).contains(&name.len()),
+            GameErrorCode::InvalidNameLength
+        );
+        require!(
+            name.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+            GameErrorCode::InvalidNameCharacters
+        );
+        Ok(())
+    }
+}
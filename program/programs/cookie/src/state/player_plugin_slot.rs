@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{calculate_accumulated_cookies, calculate_frenzy_bonus};
+use crate::state::game_config::GameConfig;
+use crate::state::player_data::PlayerData;
+use crate::state::plugin::Plugin;
+
 /// Player's plugin slot - each player has 10 slots (one per tier)
 /// Represents an instance of a plugin installed by a player
 #[account]
+#[derive(InitSpace)]
 pub struct PlayerPluginSlot {
+    /// Account layout version
+    pub version: u8,
+
     /// The player who owns this slot
     pub player: Pubkey,
 
@@ -27,8 +36,93 @@ pub struct PlayerPluginSlot {
 }
 
 impl PlayerPluginSlot {
-    /// Calculate space needed for this account
-    /// 8 (discriminator) + 32 (player) + 1 (tier) + 8 (plugin_id) +
-    /// 8 (installed_at) + 8 (last_claim) + 8 (total_claimed) + 1 (bump)
-    pub const LEN: usize = 8 + 32 + 1 + 8 + 8 + 8 + 8 + 1;
+    pub const VERSION: u8 = 1;
+
+    /// Space needed for this account: 8 (discriminator) + fields
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Cookies produced since the last claim, including the player's frenzy
+    /// bonus. Every instruction that settles a slot goes through this.
+    /// Empty slots and banned plugins produce nothing.
+    pub fn pending_cookies(
+        &self,
+        plugin: &Plugin,
+        config: &GameConfig,
+        player: &PlayerData,
+        current_time: i64,
+    ) -> u64 {
+        if self.plugin_id == 0 || !plugin.is_producing() {
+            return 0;
+        }
+
+        calculate_accumulated_cookies(config, self.tier, self.last_claim, current_time)
+            .saturating_add(calculate_frenzy_bonus(
+                config,
+                self.tier,
+                self.last_claim,
+                current_time,
+                player.frenzy_start,
+                player.frenzy_end,
+                player.frenzy_multiplier_bps,
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::plugin::PluginStatus;
+
+    fn config() -> GameConfig {
+        let mut config = GameConfig::default();
+        config.set_defaults();
+        config
+    }
+
+    fn slot(plugin_id: u64) -> PlayerPluginSlot {
+        PlayerPluginSlot {
+            version: PlayerPluginSlot::VERSION,
+            player: Pubkey::default(),
+            tier: 1,
+            plugin_id,
+            installed_at: 0,
+            last_claim: 0,
+            total_claimed: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn pending_cookies_include_the_frenzy_bonus() {
+        let config = config();
+        let plugin = Plugin {
+            plugin_id: 1,
+            ..Default::default()
+        };
+        let mut player = PlayerData::default();
+
+        // Tier 1 makes 500 cookies an hour
+        assert_eq!(slot(1).pending_cookies(&plugin, &config, &player, 3600), 500);
+
+        // A 2x frenzy over the whole hour doubles it
+        player.frenzy_start = 0;
+        player.frenzy_end = 3600;
+        player.frenzy_multiplier_bps = 20000;
+        assert_eq!(slot(1).pending_cookies(&plugin, &config, &player, 3600), 1000);
+    }
+
+    #[test]
+    fn empty_slots_and_banned_plugins_produce_nothing() {
+        let config = config();
+        let player = PlayerData::default();
+        let mut plugin = Plugin {
+            plugin_id: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(slot(0).pending_cookies(&plugin, &config, &player, 3600), 0);
+
+        plugin.status = PluginStatus::Banned;
+        assert_eq!(slot(1).pending_cookies(&plugin, &config, &player, 3600), 0);
+    }
 }
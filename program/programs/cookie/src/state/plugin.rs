@@ -1,9 +1,30 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::GameErrorCode;
+
+/// Whether a plugin can be installed and whether it still produces
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Default)]
+pub enum PluginStatus {
+    /// Listed and installable
+    #[default]
+    Active,
+    /// Pulled from the shop by its creator; existing installs keep producing
+    Delisted,
+    /// Pulled from the shop by a moderator; existing installs keep producing
+    Hidden,
+    /// Pulled by a moderator; existing installs stop producing
+    Banned,
+}
 
 /// Plugin account - represents a player-created plugin template
 /// One plugin can be installed by many players
 #[account]
+#[derive(InitSpace, Default)]
 pub struct Plugin {
+    /// Account layout version
+    pub version: u8,
+
     /// Unique sequential ID for this plugin
     pub plugin_id: u64,
 
@@ -14,6 +35,7 @@ pub struct Plugin {
     pub tier: u8,
 
     /// URI to JSON metadata (icon, background, name, description)
+    #[max_len(200)]
     pub metadata_uri: String,
 
     /// Total number of times this plugin has been installed
@@ -33,12 +55,162 @@ pub struct Plugin {
 
     /// Bump seed for PDA
     pub bump: u8,
+
+    /// Creator share that replaces `creator_share_bps` at `share_change_at`
+    pub pending_creator_share_bps: u16,
+
+    /// When the pending share takes effect (0 = no change scheduled)
+    pub share_change_at: i64,
+
+    /// Listing and moderation status
+    pub status: PluginStatus,
+
+    /// Sum of all player ratings (1-5 each)
+    pub rating_sum: u64,
+
+    /// Number of players who rated this plugin
+    pub rating_count: u64,
+
+    /// Mint of the plugin's 1-of-1 NFT; its holder receives the install revenue
+    pub nft_mint: Option<Pubkey>,
+
+    /// Number of player slots that currently have this plugin installed
+    pub active_installs: u64,
 }
 
 impl Plugin {
-    /// Calculate space needed for this account
-    /// 8 (discriminator) + 8 (plugin_id) + 32 (creator) + 1 (tier) +
-    /// 4 + 200 (metadata_uri) + 8 (total_installs) + 8 (creator_earnings) +
-    /// 8 (created_at) + 2 (creator_share_bps) + 2 (burn_share_bps) + 1 (bump)
-    pub const LEN: usize = 8 + 8 + 32 + 1 + 4 + 200 + 8 + 8 + 8 + 2 + 2 + 1;
+    pub const VERSION: u8 = 1;
+
+    /// Space needed for this account: 8 (discriminator) + fields
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+
+    /// Installed copies produce cookies unless the plugin is banned
+    pub fn is_producing(&self) -> bool {
+        self.status != PluginStatus::Banned
+    }
+
+    /// Who owns the plugin's rights: the holder of its NFT if it has one,
+    /// otherwise the creator. The holder is proven by their token account.
+    pub fn rights_holder(&self, nft_holder_account: Option<&TokenAccount>) -> Result<Pubkey> {
+        match self.nft_mint {
+            Some(nft_mint) => {
+                let holder = nft_holder_account.ok_or(GameErrorCode::MissingNftAccount)?;
+                require!(
+                    holder.mint == nft_mint && holder.amount == 1,
+                    GameErrorCode::NotNftHolder
+                );
+                Ok(holder.owner)
+            }
+            None => Ok(self.creator),
+        }
+    }
+
+    /// Add a player's rating, replacing their earlier one if they had rated before
+    pub fn record_rating(&mut self, previous: Option<u8>, rating: u8) {
+        match previous {
+            Some(old) => {
+                self.rating_sum = self.rating_sum.saturating_sub(old as u64);
+            }
+            None => {
+                self.rating_count = self.rating_count.saturating_add(1);
+            }
+        }
+        self.rating_sum = self.rating_sum.saturating_add(rating as u64);
+    }
+
+    /// Take a player's rating back out, e.g. when they close their account
+    pub fn remove_rating(&mut self, rating: u8) {
+        self.rating_sum = self.rating_sum.saturating_sub(rating as u64);
+        self.rating_count = self.rating_count.saturating_sub(1);
+    }
+
+    /// Schedule a new creator share after the notice period
+    pub fn schedule_share_change(&mut self, creator_share_bps: u16, now: i64, notice_seconds: i64) {
+        self.pending_creator_share_bps = creator_share_bps;
+        self.share_change_at = now.saturating_add(notice_seconds).max(1);
+    }
+
+    /// Switch to the pending creator share once its notice period is over
+    pub fn apply_pending_share(&mut self, now: i64) {
+        if self.share_change_at != 0 && now >= self.share_change_at {
+            self.creator_share_bps = self.pending_creator_share_bps;
+            self.burn_share_bps = 10000 - self.pending_creator_share_bps;
+            self.pending_creator_share_bps = 0;
+            self.share_change_at = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin() -> Plugin {
+        Plugin {
+            version: Plugin::VERSION,
+            plugin_id: 1,
+            creator: Pubkey::default(),
+            tier: 1,
+            metadata_uri: String::new(),
+            total_installs: 0,
+            creator_earnings: 0,
+            created_at: 0,
+            creator_share_bps: 2000,
+            burn_share_bps: 8000,
+            bump: 0,
+            pending_creator_share_bps: 0,
+            share_change_at: 0,
+            status: PluginStatus::Active,
+            rating_sum: 0,
+            rating_count: 0,
+            nft_mint: None,
+            active_installs: 0,
+        }
+    }
+
+    #[test]
+    fn share_change_waits_for_notice_period() {
+        let mut plugin = plugin();
+        plugin.schedule_share_change(5000, 1_000, 600);
+
+        plugin.apply_pending_share(1_599);
+        assert_eq!(plugin.creator_share_bps, 2000);
+
+        plugin.apply_pending_share(1_600);
+        assert_eq!(plugin.creator_share_bps, 5000);
+        assert_eq!(plugin.burn_share_bps, 5000);
+        assert_eq!(plugin.share_change_at, 0);
+    }
+
+    #[test]
+    fn rerating_replaces_the_old_rating() {
+        let mut plugin = plugin();
+        plugin.record_rating(None, 4);
+        plugin.record_rating(None, 2);
+        plugin.record_rating(Some(2), 5);
+
+        assert_eq!(plugin.rating_count, 2);
+        assert_eq!(plugin.rating_sum, 9);
+    }
+
+    #[test]
+    fn removed_rating_leaves_the_others() {
+        let mut plugin = plugin();
+        plugin.record_rating(None, 4);
+        plugin.record_rating(None, 2);
+        plugin.remove_rating(4);
+
+        assert_eq!(plugin.rating_count, 1);
+        assert_eq!(plugin.rating_sum, 2);
+    }
+
+    #[test]
+    fn zero_share_can_be_scheduled() {
+        let mut plugin = plugin();
+        plugin.schedule_share_change(0, 1_000, 0);
+
+        plugin.apply_pending_share(1_000);
+        assert_eq!(plugin.creator_share_bps, 0);
+        assert_eq!(plugin.burn_share_bps, 10000);
+    }
 }
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// A player's rating of a plugin - PDA seeded by ("plugin_rating", plugin, player)
+/// Created unrated when the player first installs the plugin, which is what
+/// proves they used it.
+#[account]
+#[derive(InitSpace)]
+pub struct PluginRating {
+    /// Plugin being rated
+    pub plugin: Pubkey,
+
+    /// Player who rated it
+    pub player: Pubkey,
+
+    /// Rating from 1 to 5 (0 = not rated yet)
+    pub rating: u8,
+
+    /// When the rating was last set
+    pub rated_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PluginRating {
+    pub const MIN_RATING: u8 = 1;
+    pub const MAX_RATING: u8 = 5;
+
+    /// Space needed for this account: 8 (discriminator) + fields
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
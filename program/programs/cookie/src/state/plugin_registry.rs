@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide plugin registry - singleton PDA seeded by "plugin_registry"
+/// Hands out plugin IDs so every `Plugin` PDA is unique across all creators
+#[account]
+#[derive(InitSpace)]
+pub struct PluginRegistry {
+    /// ID the next created plugin gets (0 means "no plugin", so IDs start at 1)
+    pub next_plugin_id: u64,
+
+    /// Number of plugins created, including those migrated at init
+    pub total_plugins: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl PluginRegistry {
+    /// Space needed for this account: 8 (discriminator) + fields
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Program-level admin role - singleton PDA seeded by "admin"
+/// Gates every privileged instruction (mint setup, config changes, ...)
+#[account]
+pub struct ProgramAdmin {
+    /// Current admin key
+    pub authority: Pubkey,
+
+    /// Admin proposed via `propose_admin`, waiting to call `accept_admin`
+    pub pending_authority: Option<Pubkey>,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ProgramAdmin {
+    /// Calculate space needed for this account
+    /// 8 (discriminator) + 32 (authority) + 1 + 32 (pending_authority) + 1 (bump)
+    pub const LEN: usize = 8 + 32 + 1 + 32 + 1;
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::GameErrorCode;
+
+/// What crafting a recipe gives the player
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RecipeOutput {
+    /// One of an item, counted in the player's `PlayerItem` account
+    Item { item_id: u32 },
+    /// Energy, up to the player's energy cap
+    EnergyRefill { amount: u64 },
+    /// Temporary production boost, works like a golden cookie frenzy
+    ProductionBoost {
+        multiplier_bps: u32,
+        duration_seconds: i64,
+    },
+}
+
+impl RecipeOutput {
+    /// Item id if this output is an item
+    pub fn item_id(&self) -> Option<u32> {
+        match self {
+            RecipeOutput::Item { item_id } => Some(*item_id),
+            _ => None,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            RecipeOutput::Item { .. } => {}
+            RecipeOutput::EnergyRefill { amount } => {
+                require!(amount > 0, GameErrorCode::InvalidRecipe);
+            }
+            RecipeOutput::ProductionBoost {
+                multiplier_bps,
+                duration_seconds,
+            } => {
+                require!(multiplier_bps > 10000, GameErrorCode::InvalidRecipe);
+                require!(duration_seconds > 0, GameErrorCode::InvalidRecipe);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Crafting recipe registered by the admin - PDA seeded by "recipe" and recipe_id
+#[account]
+#[derive(InitSpace)]
+pub struct Recipe {
+    /// ID chosen by the admin
+    pub recipe_id: u64,
+
+    /// Wood tokens burned per craft
+    pub wood_cost: u64,
+
+    /// Cookie tokens burned per craft
+    pub cookie_cost: u64,
+
+    /// What the player gets
+    pub output: RecipeOutput,
+
+    /// Disabled recipes can't be crafted
+    pub enabled: bool,
+
+    /// Number of times this recipe was crafted
+    pub times_crafted: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl Recipe {
+    /// Space needed for this account: 8 (discriminator) + fields
+    pub const LEN: usize = 8 + Self::INIT_SPACE;
+}
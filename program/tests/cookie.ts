@@ -37,6 +37,31 @@ describe("cookie", () => {
     program.programId
   );
 
+  // Wood token metadata
+  const woodMetadata = {
+    uri: "https://raw.githubusercontent.com/solana-developers/program-examples/main/tokens/tokens/.assets/spl-token.png",
+    name: "Wood Token",
+    symbol: "WOOD",
+  };
+
+  // Wood token mint PDA
+  const [woodMintPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("wood")],
+    program.programId
+  );
+
+  // Program admin PDA
+  const [programAdminPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("admin")],
+    program.programId
+  );
+
+  // Global game config PDA
+  const [gameConfigPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+
   // Helper function to log and confirm transactions
   async function logTransaction(txHash: string) {
     const { blockhash, lastValidBlockHeight } =
@@ -51,6 +76,97 @@ describe("cookie", () => {
     console.log(`✅ Transaction confirmed: ${txHash}`);
   }
 
+  // Chops and clicks must use a counter above the player's last one
+  async function nextCounter(playerPDA: web3.PublicKey) {
+    const player = await program.account.playerData.fetch(playerPDA);
+    return (player.lastId + 1) % 65536;
+  }
+
+  it("Initializes the program admin", async () => {
+    try {
+      await program.account.programAdmin.fetch(programAdminPDA);
+      console.log("✅ Program Admin Already Exists");
+    } catch {
+      // Only the upgrade authority (the deploying wallet) may do this
+      const [programDataPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        new web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+      );
+
+      const txHash = await program.methods
+        .initAdmin()
+        .accountsStrict({
+          signer: payer.publicKey,
+          programAdmin: programAdminPDA,
+          program: program.programId,
+          programData: programDataPDA,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await logTransaction(txHash);
+    }
+
+    const programAdmin = await program.account.programAdmin.fetch(
+      programAdminPDA
+    );
+    console.log("🔑 Admin:", programAdmin.authority.toBase58());
+  });
+
+  it("Initializes the game config", async () => {
+    try {
+      await program.account.gameConfig.fetch(gameConfigPDA);
+      console.log("✅ Game Config Already Exists");
+    } catch {
+      const txHash = await program.methods
+        .initConfig()
+        .accountsStrict({
+          admin: payer.publicKey,
+          programAdmin: programAdminPDA,
+          gameConfig: gameConfigPDA,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc();
+      await logTransaction(txHash);
+    }
+
+    const config = await program.account.gameConfig.fetch(gameConfigPDA);
+    console.log("⚙️ Max Energy:", config.maxEnergy.toString());
+    console.log("⚙️ Click Energy Cost:", config.clickEnergyCost.toString());
+  });
+
+  it("Creates the Wood Token! 🪵", async () => {
+    const woodTokenMintMetadataPDA = await metaplex
+      .nfts()
+      .pdas()
+      .metadata({ mint: woodMintPDA });
+
+    try {
+      await getMint(provider.connection, woodMintPDA);
+      console.log("✅ Wood Token Mint Already Exists!");
+    } catch {
+      const txHash = await program.methods
+        .createWoodMint(
+          woodMetadata.uri,
+          woodMetadata.name,
+          woodMetadata.symbol
+        )
+        .accountsStrict({
+          admin: payer.publicKey,
+          programAdmin: programAdminPDA,
+          woodMint: woodMintPDA,
+          metadataAccount: woodTokenMintMetadataPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        })
+        .rpc();
+
+      await logTransaction(txHash);
+      console.log("🎉 Wood Token Created:", woodMintPDA.toString());
+    }
+  });
+
   it("Init player and chop tree!", async () => {
     console.log("Local address", payer.publicKey.toBase58());
 
@@ -86,6 +202,7 @@ describe("cookie", () => {
           player: playerPDA,
           signer: payer.publicKey,
           gameData: gameDataPDA,
+          gameConfig: gameConfigPDA,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc({ skipPreflight: true });
@@ -101,12 +218,21 @@ describe("cookie", () => {
       console.log(`Chop instruction ${i}`);
 
       let tx = await program.methods
-        .chopTree(gameDataSeed, 0)
+        .chopTree(gameDataSeed, await nextCounter(playerPDA), 1)
         .accountsStrict({
           player: playerPDA,
           sessionToken: null,
           signer: payer.publicKey,
           gameData: gameDataPDA,
+          gameConfig: gameConfigPDA,
+          playerAuthority: payer.publicKey,
+          playerWoodAccount: getAssociatedTokenAddressSync(
+            woodMintPDA,
+            payer.publicKey
+          ),
+          woodMint: woodMintPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc();
@@ -149,6 +275,7 @@ describe("cookie", () => {
         .createMint(metadata.uri, metadata.name, metadata.symbol)
         .accounts({
           admin: payer.publicKey,
+          programAdmin: programAdminPDA,
           rewardTokenMint: cookieMintPDA,
           metadataAccount: cookieTokenMintMetadataPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -164,6 +291,28 @@ describe("cookie", () => {
     }
   });
 
+  it("Updates the Cookie Token metadata", async () => {
+    const cookieTokenMintMetadataPDA = await metaplex
+      .nfts()
+      .pdas()
+      .metadata({ mint: cookieMintPDA });
+
+    // Leave the metadata mutable so the test can be re-run
+    const txHash = await program.methods
+      .updateMintMetadata(metadata.uri, metadata.name, metadata.symbol, false)
+      .accountsStrict({
+        admin: payer.publicKey,
+        programAdmin: programAdminPDA,
+        rewardTokenMint: cookieMintPDA,
+        metadataAccount: cookieTokenMintMetadataPDA,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      })
+      .rpc();
+
+    await logTransaction(txHash);
+    console.log("🎉 Cookie Token Metadata Updated!");
+  });
+
   it("Clicks the cookie and earns tokens! 🖱️🍪", async () => {
     console.log("\n🖱️ Testing Cookie Clicking Mechanism...");
 
@@ -220,6 +369,7 @@ describe("cookie", () => {
           player: playerPDA,
           signer: payer.publicKey,
           gameData: gameDataPDA,
+          gameConfig: gameConfigPDA,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
         .rpc();
@@ -235,9 +385,10 @@ describe("cookie", () => {
       console.log(`\n--- Click ${i + 1} ---`);
 
       txHash = await program.methods
-        .onClick()
+        .onClick(await nextCounter(playerPDA))
         .accounts({
           player: playerPDA,
+          gameConfig: gameConfigPDA,
           playerAuthority: payer.publicKey,
           playerTokenAccount: playerTokenAccount,
           rewardTokenMint: cookieMintPDA,
@@ -260,4 +411,44 @@ describe("cookie", () => {
     console.log("\n🎉 Final Stats:");
     await fetchAccountData();
   });
+
+  it("Clicks the cookie in a batch 🖱️🖱️🖱️", async () => {
+    const [playerPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("player"), payer.publicKey.toBuffer()],
+      program.programId
+    );
+    const playerTokenAccount = getAssociatedTokenAddressSync(
+      cookieMintPDA,
+      payer.publicKey
+    );
+
+    const before = await provider.connection.getTokenAccountBalance(
+      playerTokenAccount
+    );
+
+    // One transaction for 5 buffered clicks
+    const txHash = await program.methods
+      .onClickBatch(5, await nextCounter(playerPDA))
+      .accounts({
+        player: playerPDA,
+        gameConfig: gameConfigPDA,
+        playerAuthority: payer.publicKey,
+        playerTokenAccount: playerTokenAccount,
+        rewardTokenMint: cookieMintPDA,
+        signer: payer.publicKey,
+        sessionToken: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+    await logTransaction(txHash);
+
+    const after = await provider.connection.getTokenAccountBalance(
+      playerTokenAccount
+    );
+    console.log(
+      `🍪 Batch earned ${after.value.uiAmount - before.value.uiAmount} COOKIE`
+    );
+  });
 });